@@ -0,0 +1,20 @@
+use aoc::DAYS;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    for day in DAYS {
+        let mut group = c.benchmark_group(format!("day-{}", day.number));
+        let parsed = day.parse(day.input).unwrap();
+
+        group.bench_function("parse", |b| {
+            b.iter(|| day.parse(black_box(day.input)).unwrap())
+        });
+        group.bench_function("part 1", |b| b.iter(|| parsed.part1().unwrap()));
+        group.bench_function("part 2", |b| b.iter(|| parsed.part2().unwrap()));
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+use common::Day;
+
+pub const DAYS: [Day; 11] = [
+    day_1::DAY,
+    day_2::DAY,
+    day_3::DAY,
+    day_4::DAY,
+    day_5::DAY,
+    day_6::DAY,
+    day_7::DAY,
+    day_8::DAY,
+    day_9::DAY,
+    day_10::DAY,
+    day_11::DAY,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        for (day, number) in DAYS.iter().zip(1..) {
+            assert_eq!(day.number, number);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::{env, ops::RangeInclusive, process::ExitCode};
+
+use aoc::DAYS;
+use common::{input, timing::timed};
+
+const USAGE: &str = "usage: aoc <all | DAY | FROM..TO> [--part 1|2] [--input PATH|-] [--time]";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Selection {
+    days: RangeInclusive<u8>,
+    parts: RangeInclusive<u8>,
+    input: Option<String>,
+    time: bool,
+}
+
+fn parse_days(s: &str) -> Option<RangeInclusive<u8>> {
+    if s == "all" {
+        return Some(1..=u8::MAX);
+    }
+
+    match s.split_once("..").or_else(|| s.split_once('-')) {
+        Some((from, to)) => {
+            let to = to.strip_prefix('=').unwrap_or(to);
+            Some(from.parse().ok()?..=to.parse().ok()?)
+        }
+        None => {
+            let day = s.parse().ok()?;
+            Some(day..=day)
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Selection, String> {
+    let mut days = None;
+    let mut parts = 1..=2;
+    let mut input = None;
+    let mut time = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args
+                    .next()
+                    .and_then(|part| part.parse().ok())
+                    .filter(|part| (1..=2).contains(part))
+                    .ok_or_else(|| "--part expects 1 or 2".to_string())?;
+                parts = part..=part;
+            }
+            "--time" | "-t" => time = true,
+            "--input" | "-i" => {
+                input = Some(
+                    args.next()
+                        .cloned()
+                        .ok_or_else(|| "--input expects a path".to_string())?,
+                );
+            }
+            arg if days.is_none() => {
+                days = Some(parse_days(arg).ok_or_else(|| format!("invalid day {}", arg))?);
+            }
+            arg => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    let days = days.ok_or_else(|| "no day given".to_string())?;
+    if input.is_some() && days.start() != days.end() {
+        return Err("--input requires a single day".to_string());
+    }
+
+    Ok(Selection {
+        days,
+        parts,
+        input,
+        time,
+    })
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let selection = match parse_args(&args) {
+        Ok(selection) => selection,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let days: Vec<_> = DAYS
+        .iter()
+        .filter(|day| selection.days.contains(&day.number))
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("no solutions for days {:?}", selection.days);
+        return ExitCode::FAILURE;
+    }
+
+    // A day that doesn't parse or a part without an answer doesn't stop the
+    // others from running.
+    let mut failed = false;
+
+    for day in days {
+        let input = match &selection.input {
+            Some(path) => match input::load(Some(path), day.input) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            },
+            None => day.input.into(),
+        };
+
+        let (parsed, duration) = timed(|| day.parse(&input));
+        if selection.time {
+            println!("Day {} parse: {:?}", day.number, duration);
+        }
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("Day {}: {}", day.number, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        for part in selection.parts.clone() {
+            let (answer, duration) = timed(|| parsed.part(part).unwrap());
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("Day {} part {}: {}", day.number, part, err);
+                    failed = true;
+                    continue;
+                }
+            };
+
+            if selection.time {
+                println!(
+                    "Day {} part {}: {} ({:?})",
+                    day.number, part, answer, duration
+                );
+            } else {
+                println!("Day {} part {}: {}", day.number, part, answer);
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args("5 --part 2")),
+            Ok(Selection {
+                days: 5..=5,
+                parts: 2..=2,
+                input: None,
+                time: false
+            })
+        );
+        assert_eq!(
+            parse_args(&args("3..7")),
+            Ok(Selection {
+                days: 3..=7,
+                parts: 1..=2,
+                input: None,
+                time: false
+            })
+        );
+        assert_eq!(
+            parse_args(&args("9 --input example")).map(|s| s.input),
+            Ok(Some("example".to_string()))
+        );
+        assert!(parse_args(&args("all --input example")).is_err());
+        assert_eq!(parse_args(&args("all --time")).map(|s| s.time), Ok(true));
+        assert_eq!(parse_args(&args("all")).map(|s| s.days), Ok(1..=u8::MAX));
+        assert!(parse_args(&args("5 --part 3")).is_err());
+        assert!(parse_args(&args("five")).is_err());
+        assert!(parse_args(&args("")).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{Answer, ParseError, Solution};
+
+pub trait Parsed {
+    fn part1(&self) -> Answer<String>;
+    fn part2(&self) -> Answer<String>;
+
+    fn part(&self, part: u8) -> Option<Answer<String>> {
+        match part {
+            1 => Some(self.part1()),
+            2 => Some(self.part2()),
+            _ => None,
+        }
+    }
+}
+
+struct Input<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Input<S> {
+    fn part1(&self) -> Answer<String> {
+        S::part1(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self) -> Answer<String> {
+        S::part2(&self.0).map(|answer| answer.to_string())
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+    Ok(Box::new(Input::<S>(S::parse(input)?)))
+}
+
+// A type-erased `Solution` together with its embedded input, so that all days
+// can be kept in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Parsed>, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(input: &'static str) -> Self {
+        Self {
+            number: S::DAY,
+            input,
+            parse: parse::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        (self.parse)(input)
+    }
+}
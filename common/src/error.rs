@@ -0,0 +1,115 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    // An error about `text`, which has to be a slice of `source`, the
+    // `line`th line of the input. The column is derived from where `text`
+    // starts within `source`.
+    pub fn at(line: usize, source: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+        let column = if offset <= source.len() {
+            source[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        Self::new(line, column, text, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} at `{}`",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Why solving a puzzle failed: either the input didn't parse, or it parsed but
+// one of the parts couldn't produce an answer from it.
+#[derive(Debug)]
+pub enum SolveError {
+    Parse(ParseError),
+    Part(Box<dyn Error>),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<Box<dyn Error>> for SolveError {
+    fn from(err: Box<dyn Error>) -> Self {
+        Self::Part(err)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => err.fmt(f),
+            Self::Part(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+// Part errors are compared by their message, which is all that tests can
+// reasonably expect to match on.
+impl PartialEq for SolveError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Parse(a), Self::Parse(b)) => a == b,
+            (Self::Part(a), Self::Part(b)) => a.to_string() == b.to_string(),
+            _ => false,
+        }
+    }
+}
+
+pub fn number<T: FromStr>(line: usize, source: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(line, source, text, "expected a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let source = "Time:      7  15   3x";
+        let text = source.split_whitespace().last().unwrap();
+
+        assert_eq!(
+            number::<u32>(2, source, text),
+            Err(ParseError::new(2, 20, "3x", "expected a number"))
+        );
+        assert_eq!(
+            number::<u32>(2, source, text).unwrap_err().to_string(),
+            "line 2, column 20: expected a number at `3x`"
+        );
+        assert_eq!(ParseError::at(1, source, "elsewhere", "oops").column, 1);
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    fmt,
+    ops::{Add, Index, IndexMut, Sub},
+};
+
+use crate::ParseError;
+
+#[derive(Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Position {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Position> {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| self + dir.offset())
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Position> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Position::new(x, y)))
+            .filter(|offset| *offset != Position::default())
+            .map(move |offset| self + offset)
+    }
+
+    pub fn manhattan(self, other: Position) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Position {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl From<(i64, i64)> for Position {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn counterpart(&self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn offset(&self) -> Position {
+        match self {
+            Direction::North => (0, -1).into(),
+            Direction::East => (1, 0).into(),
+            Direction::South => (0, 1).into(),
+            Direction::West => (-1, 0).into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len());
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Every line becomes a row; all rows must have the same length.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(s, |c| Some(f(c)))
+    }
+
+    // Like `parse`, with `f` rejecting characters by returning `None`.
+    pub fn try_parse(s: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (line, y) in s.lines().zip(1..) {
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::at(y, line, &line[i..i + c.len_utf8()], "unexpected character")
+                })?;
+                cells.push(cell);
+            }
+
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return Err(ParseError::new(
+                    y,
+                    1,
+                    line,
+                    format!("expected a row of width {}", width.unwrap()),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        (0..self.width as i64).contains(&pos.x) && (0..self.height as i64).contains(&pos.y)
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.neighbours4()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.neighbours8()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", pos))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#..\n.#.\n..#\n.#.\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(EXAMPLE, |c| c == '#').unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert!(grid[Position::new(1, 1)]);
+        assert!(!grid[Position::new(1, 2)]);
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.get(Position::new(0, -1)), None);
+        assert_eq!(grid.find(|cell| *cell), Some(Position::new(0, 0)));
+        assert_eq!(Grid::parse(EXAMPLE, |c| c).unwrap().to_string(), EXAMPLE);
+        assert_eq!(
+            Grid::parse("..\n...\n", |c| c),
+            Err(ParseError::new(2, 1, "...", "expected a row of width 2"))
+        );
+        assert_eq!(
+            Grid::try_parse("..\n.x\n", |c| (c == '.').then_some(c)),
+            Err(ParseError::new(2, 2, "x", "unexpected character"))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(EXAMPLE, |c| c == '#').unwrap();
+
+        assert_eq!(Position::new(5, 5).neighbours4().count(), 4);
+        assert_eq!(Position::new(5, 5).neighbours8().count(), 8);
+        assert_eq!(grid.neighbours4(Position::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Position::new(0, 0)).count(), 3);
+        assert_eq!(
+            grid.neighbours8(Position::new(1, 1))
+                .filter(|(_, cell)| **cell)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            vec![Position::new(0, 0), Position::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.counterpart().counterpart(), dir);
+            assert_eq!(
+                dir.offset() + dir.counterpart().offset(),
+                Position::default()
+            );
+        }
+
+        assert_eq!(
+            Position::new(1, 2) - Position::new(4, -2),
+            Position::new(-3, 4)
+        );
+        assert_eq!(Position::new(1, 2).manhattan(Position::new(4, -2)), 7);
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, Read},
+    process,
+};
+
+fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+// Reads the puzzle input from `path`, or from stdin if `path` is `-`. With no
+// path, the input is `embedded`; stdin is never read unless asked for, since it
+// may be an open pipe that nothing writes to.
+pub fn load(path: Option<&str>, embedded: &'static str) -> io::Result<Cow<'static, str>> {
+    match path {
+        Some("-") => read_stdin().map(Cow::Owned),
+        Some(path) => fs::read_to_string(path)
+            .map(Cow::Owned)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path, err))),
+        None => Ok(Cow::Borrowed(embedded)),
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub path: Option<String>,
+    pub time: bool,
+    // Day-specific flags in the order given, each with its value if it had one.
+    pub flags: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Self {
+        Self::parse_with(args, &[])
+    }
+
+    // Like `parse`, but also accepts `--<flag>` and `--<flag>=<value>` for each
+    // of the given flags instead of taking them for the path.
+    pub fn parse_with(args: impl IntoIterator<Item = String>, flags: &[&str]) -> Self {
+        let mut result = Self::default();
+
+        for arg in args {
+            let flag = arg.strip_prefix("--").and_then(|flag| {
+                let (name, value) = match flag.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (flag, None),
+                };
+                flags.contains(&name).then(|| (name.to_string(), value))
+            });
+
+            match (arg.as_str(), flag) {
+                (_, Some(flag)) => result.flags.push(flag),
+                ("--time" | "-t", _) => result.time = true,
+                _ => result.path = Some(arg),
+            }
+        }
+
+        result
+    }
+
+    pub fn from_env() -> Self {
+        Self::parse(env::args().skip(1))
+    }
+
+    pub fn from_env_with(flags: &[&str]) -> Self {
+        Self::parse_with(env::args().skip(1), flags)
+    }
+
+    // None if the flag wasn't given, otherwise the value it was last given
+    // with, if any.
+    pub fn flag(&self, name: &str) -> Option<Option<&str>> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(flag, _)| flag == name)
+            .map(|(_, value)| value.as_deref())
+    }
+
+    // `load` with the given path, exiting with the error message if the input
+    // can't be read.
+    pub fn input(&self, embedded: &'static str) -> Cow<'static, str> {
+        load(self.path.as_deref(), embedded).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join("aoc-common-input-test");
+        fs::write(&path, "1 2 3\n").unwrap();
+
+        assert_eq!(load(path.to_str(), "embedded").unwrap(), "1 2 3\n");
+        assert!(load(Some("/nonexistent/input"), "embedded").is_err());
+        assert_eq!(load(None, "embedded").unwrap(), "embedded");
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_args() {
+        let args = |s: &str| Args::parse(s.split_whitespace().map(String::from));
+
+        assert_eq!(args(""), Args::default());
+        assert_eq!(
+            args("--time example"),
+            Args {
+                path: Some("example".to_string()),
+                time: true,
+                flags: vec![]
+            }
+        );
+        assert_eq!(args("-").path.as_deref(), Some("-"));
+
+        let args = |s: &str| Args::parse_with(s.split_whitespace().map(String::from), &["dot"]);
+        let dot = args("--dot example --dot=path");
+        assert_eq!(dot.path.as_deref(), Some("example"));
+        assert_eq!(dot.flag("dot"), Some(Some("path")));
+        assert_eq!(args("--dot").flag("dot"), Some(None));
+        assert_eq!(args("example").flag("dot"), None);
+        assert_eq!(args("--dots").path.as_deref(), Some("--dots"));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod day;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod solution;
+pub mod timing;
+
+pub use day::Day;
+pub use error::{ParseError, SolveError};
+pub use grid::{Direction, Grid, Position};
+pub use solution::{run, Answer, Solution};
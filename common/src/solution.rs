@@ -0,0 +1,65 @@
+use std::{error::Error, fmt::Display, process::ExitCode};
+
+use crate::{error::SolveError, timing::timed, ParseError};
+
+// What a part returns: its answer, or why the parsed input has none.
+pub type Answer<T> = Result<T, Box<dyn Error>>;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Answer<Self::Answer2>;
+
+    fn solve1(input: &str) -> Result<Self::Answer1, SolveError> {
+        Ok(Self::part1(&Self::parse(input)?)?)
+    }
+
+    fn solve2(input: &str) -> Result<Self::Answer2, SolveError> {
+        Ok(Self::part2(&Self::parse(input)?)?)
+    }
+}
+
+pub fn run<S: Solution>(input: &str, time: bool) -> ExitCode {
+    let (parsed, parse) = timed(|| S::parse(input));
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (answer, part1) = timed(|| S::part1(&parsed));
+    let ok1 = report(answer);
+    let (answer, part2) = timed(|| S::part2(&parsed));
+    let ok2 = report(answer);
+
+    if time {
+        eprintln!(
+            "parse: {:?}, part 1: {:?}, part 2: {:?}",
+            parse, part1, part2
+        );
+    }
+
+    if ok1 && ok2 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// Prints an answer to stdout, or why there is none to stderr.
+fn report<T: Display>(answer: Answer<T>) -> bool {
+    match &answer {
+        Ok(answer) => println!("{}", answer),
+        Err(err) => eprintln!("{}", err),
+    }
+    answer.is_ok()
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+
+use common::{Answer, Day, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const INPUT: &str = include_str!("input");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .zip(1..)
+            .map(
+                |(line, y)| match line.find(|c: char| !c.is_ascii_alphanumeric()) {
+                    Some(i) => Err(ParseError::at(
+                        y,
+                        line,
+                        &line[i..],
+                        "expected only letters and digits",
+                    )),
+                    None => Ok(line.to_string()),
+                },
+            )
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u16> {
+        input
+            .iter()
+            .zip(1..)
+            .map(|(s, y)| {
+                let mut digits = s.chars().filter_map(|c| c.to_digit(10));
+                let first = digits.next().ok_or_else(|| no_digit(y, s))?;
+                let last = digits.next_back().unwrap_or(first);
+                Ok((first * 10 + last) as u16)
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u16> {
+        lazy_static! {
+            static ref RE_FIRST: Regex =
+                Regex::new(r"(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
+            static ref RE_LAST: Regex =
+                Regex::new(r"(\d|enin|thgie|neves|xis|evif|ruof|eerht|owt|eno)").unwrap();
+        }
+
+        input
+            .iter()
+            .zip(1..)
+            .map(|(s, y)| {
+                let first = RE_FIRST.find(s).ok_or_else(|| no_digit(y, s))?;
+                let reversed: String = s.chars().rev().collect();
+                let last: String = RE_LAST
+                    .find(&reversed)
+                    .ok_or_else(|| no_digit(y, s))?
+                    .as_str()
+                    .chars()
+                    .rev()
+                    .collect();
+
+                let [first, last] = [first.as_str(), last.as_str()].map(|s| match s {
+                    "1" | "one" => 1,
+                    "2" | "two" => 2,
+                    "3" | "three" => 3,
+                    "4" | "four" => 4,
+                    "5" | "five" => 5,
+                    "6" | "six" => 6,
+                    "7" | "seven" => 7,
+                    "8" | "eight" => 8,
+                    "9" | "nine" => 9,
+                    _ => 0,
+                });
+
+                Ok(first * 10 + last)
+            })
+            .sum()
+    }
+}
+
+// Part 2 can solve lines that only spell their digits out, so `parse` accepts
+// lines without digits and each part reports the ones it can't solve.
+fn no_digit(y: usize, line: &str) -> Box<dyn Error> {
+    ParseError::new(y, 1, line, "expected a digit").into()
+}
+
+pub const DAY: Day = Day::new::<Day1>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example-1");
+    const EXAMPLE2: &str = include_str!("example-2");
+
+    #[test]
+    fn test_example1() {
+        assert_eq!(Day1::solve1(EXAMPLE1), Ok(142));
+    }
+
+    #[test]
+    fn test_example2() {
+        assert_eq!(Day1::solve2(EXAMPLE2), Ok(281));
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let input = Day1::parse("1abc2\nabc\n").unwrap();
+        assert_eq!(
+            Day1::part1(&input).map_err(|err| err.to_string()),
+            Err("line 2, column 1: expected a digit at `abc`".to_string())
+        );
+        assert!(Day1::part2(&input).is_err());
+        assert_eq!(Day1::solve1("one\n").map_err(|_| ()), Err(()));
+        assert_eq!(Day1::solve2("one\n"), Ok(11));
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::HashSet;
+
+use common::{Answer, Day, Direction, Grid, ParseError, Position, Solution};
+
+pub const INPUT: &str = include_str!("input");
+
+#[derive(PartialEq, Eq)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+    None,
+}
+
+impl From<char> for Pipe {
+    fn from(c: char) -> Self {
+        match c {
+            '|' => Self::Vertical,
+            '-' => Self::Horizontal,
+            'L' => Self::NorthEast,
+            'J' => Self::NorthWest,
+            '7' => Self::SouthWest,
+            'F' => Self::SouthEast,
+            _ => Self::None,
+        }
+    }
+}
+
+impl Pipe {
+    fn connectors(&self) -> HashSet<Direction> {
+        match self {
+            Pipe::Vertical => HashSet::from([Direction::North, Direction::South]),
+            Pipe::Horizontal => HashSet::from([Direction::East, Direction::West]),
+            Pipe::NorthEast => HashSet::from([Direction::North, Direction::East]),
+            Pipe::NorthWest => HashSet::from([Direction::North, Direction::West]),
+            Pipe::SouthEast => HashSet::from([Direction::South, Direction::East]),
+            Pipe::SouthWest => HashSet::from([Direction::South, Direction::West]),
+            Pipe::None => HashSet::new(),
+        }
+    }
+}
+
+pub struct Game {
+    map: Grid<Pipe>,
+    track: Vec<Position>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::try_parse(value, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+        let start = tiles
+            .find(|c| *c == 'S')
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing start tile"))?;
+        let mut map = tiles.map(|c| Pipe::from(*c));
+
+        let [north, east, south, west] = Direction::ALL.map(|dir| {
+            map.get(start + dir.offset())
+                .unwrap_or(&Pipe::None)
+                .connectors()
+                .contains(&dir.counterpart())
+        });
+
+        map[start] = match (north, east, south, west) {
+            (true, true, false, false) => Pipe::NorthEast,
+            (false, true, true, false) => Pipe::SouthEast,
+            (true, false, false, true) => Pipe::NorthWest,
+            (false, false, true, true) => Pipe::SouthWest,
+            (true, false, true, false) => Pipe::Vertical,
+            (false, true, false, true) => Pipe::Horizontal,
+            _ => {
+                return Err(ParseError::new(
+                    start.y as usize + 1,
+                    start.x as usize + 1,
+                    "S",
+                    "expected the start tile to connect to exactly two pipes",
+                ))
+            }
+        };
+
+        let track = trace(&map, &tiles, start)?;
+        Ok(Self { map, track })
+    }
+}
+
+// Follows the loop from the start tile until it gets back there, failing at the
+// first pipe that doesn't lead into another one connecting back to it.
+fn trace(
+    map: &Grid<Pipe>,
+    tiles: &Grid<char>,
+    start: Position,
+) -> Result<Vec<Position>, ParseError> {
+    // The start tile has been resolved to a pipe by now, so it has connectors
+    // to pick the first one from.
+    let mut direction = Direction::ALL
+        .into_iter()
+        .find(|dir| map[start].connectors().contains(dir))
+        .unwrap_or(Direction::North);
+    let mut track = vec![start];
+    let mut pos = start;
+
+    loop {
+        let next = pos + direction.offset();
+        let dirs = map.get(next).map(Pipe::connectors).unwrap_or_default();
+
+        let onward = dirs
+            .contains(&direction.counterpart())
+            .then(|| dirs.into_iter().find(|d| *d != direction.counterpart()))
+            .flatten();
+        let Some(onward) = onward else {
+            return Err(ParseError::new(
+                pos.y as usize + 1,
+                pos.x as usize + 1,
+                &tiles[pos].to_string(),
+                "expected the loop to continue from this pipe",
+            ));
+        };
+
+        if next == start {
+            return Ok(track);
+        }
+
+        track.push(next);
+        pos = next;
+        direction = onward;
+    }
+}
+
+impl Game {
+    // The positions of the loop, starting at the start tile.
+    pub fn track(&self) -> &[Position] {
+        &self.track
+    }
+
+    pub fn area(&self) -> usize {
+        let track: HashSet<_> = self.track.iter().copied().collect();
+        let space: HashSet<_> = self.map.positions().collect();
+
+        space
+            .difference(&track)
+            .filter(|pos| {
+                let mut inside = false;
+                let mut bend: Option<&Pipe> = None;
+
+                for y in (0..pos.y).rev() {
+                    let pos: Position = (pos.x, y).into();
+                    if !track.contains(&pos) {
+                        continue;
+                    }
+                    let pipe = &self.map[pos];
+
+                    match (pipe, bend) {
+                        (&Pipe::Horizontal, _) => {
+                            inside = !inside;
+                        }
+                        (&Pipe::NorthEast, _) | (&Pipe::NorthWest, _) => {
+                            bend = Some(pipe);
+                        }
+                        (&Pipe::SouthEast, Some(Pipe::NorthWest))
+                        | (&Pipe::SouthWest, Some(Pipe::NorthEast)) => {
+                            inside = !inside;
+                            bend = None;
+                        }
+                        (&Pipe::SouthEast, Some(Pipe::NorthEast))
+                        | (&Pipe::SouthWest, Some(Pipe::NorthWest)) => {
+                            bend = None;
+                        }
+                        _ => {}
+                    }
+                }
+
+                inside
+            })
+            .count()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.try_into()
+    }
+
+    fn part1(input: &Self::Input) -> Answer<usize> {
+        Ok(input.track().len() / 2)
+    }
+
+    fn part2(input: &Self::Input) -> Answer<usize> {
+        Ok(input.area())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day10>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example-1");
+    const EXAMPLE2: &str = include_str!("example-2");
+    const EXAMPLE3: &str = include_str!("example-3");
+    const EXAMPLE4: &str = include_str!("example-4");
+    const EXAMPLE5: &str = include_str!("example-5");
+    const EXAMPLE6: &str = include_str!("example-6");
+    const EXAMPLE7: &str = include_str!("example-7");
+
+    #[test]
+    fn test_example1() {
+        assert_eq!(Day10::solve1(EXAMPLE1), Ok(4));
+    }
+
+    #[test]
+    fn test_example2() {
+        assert_eq!(Day10::solve1(EXAMPLE2), Ok(4));
+    }
+
+    #[test]
+    fn test_example3() {
+        assert_eq!(Day10::solve1(EXAMPLE3), Ok(8));
+    }
+
+    #[test]
+    fn test_example4() {
+        assert_eq!(Day10::solve1(EXAMPLE4), Ok(8));
+    }
+
+    #[test]
+    fn test_example5() {
+        assert_eq!(Day10::solve2(EXAMPLE5), Ok(4));
+    }
+
+    #[test]
+    fn test_example6() {
+        assert_eq!(Day10::solve2(EXAMPLE6), Ok(8));
+    }
+
+    #[test]
+    fn test_example7() {
+        assert_eq!(Day10::solve2(EXAMPLE7), Ok(10));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day10::solve1(".....\n.S-7.\n.|.|.\n.L-J.\n....x\n").map(|_| ()),
+            Err(ParseError::new(5, 5, "x", "unexpected character").into())
+        );
+        assert_eq!(
+            Day10::solve1(".....\n.S-7.\n...|.\n.L-J.\n.....\n").map(|_| ()),
+            Err(ParseError::new(
+                2,
+                2,
+                "S",
+                "expected the start tile to connect to exactly two pipes"
+            )
+            .into())
+        );
+        assert_eq!(
+            Day10::solve1(".....\n.S-7.\n.|.|.\n.L-..\n.....\n").map(|_| ()),
+            Err(ParseError::new(3, 4, "|", "expected the loop to continue from this pipe").into())
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::{BTreeSet, HashMap};
+
+use common::{Answer, Day, Grid, ParseError, Position, Solution};
+
+pub const INPUT: &str = include_str!("input");
+
+fn gaps(coordinates: BTreeSet<i64>, age: u32) -> HashMap<i64, i64> {
+    coordinates
+        .into_iter()
+        .zip(0..)
+        .map(|(n, i)| (n, (n - i) * (age as i64 - 1)))
+        .collect()
+}
+
+fn expand(galaxies: &[Position], age: u32) -> Vec<Position> {
+    let gaps_x = gaps(galaxies.iter().map(|pos| pos.x).collect(), age);
+    let gaps_y = gaps(galaxies.iter().map(|pos| pos.y).collect(), age);
+
+    galaxies
+        .iter()
+        .map(|pos| *pos + Position::new(gaps_x[&pos.x], gaps_y[&pos.y]))
+        .collect()
+}
+
+fn solve(galaxies: &[Position], age: u32) -> u128 {
+    let mut galaxies = expand(galaxies, age);
+    let mut pairs: Vec<_> = Vec::new();
+    while let Some(left) = galaxies.pop() {
+        for right in &galaxies {
+            pairs.push((left, *right));
+        }
+    }
+
+    pairs
+        .into_iter()
+        .map(|(left, right)| left.manhattan(right) as u128)
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Position>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Grid::try_parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?
+        .iter()
+        .filter(|(_, galaxy)| **galaxy)
+        .map(|(pos, _)| pos)
+        .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u128> {
+        Ok(solve(input, 2))
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u128> {
+        Ok(solve(input, 1000000))
+    }
+}
+
+pub const DAY: Day = Day::new::<Day11>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(Day11::solve1(EXAMPLE), Ok(374));
+    }
+
+    #[test]
+    fn test_single_galaxy() {
+        assert_eq!(Day11::solve1("...\n.#.\n"), Ok(0));
+        assert_eq!(Day11::solve2("...\n"), Ok(0));
+    }
+}
@@ -0,0 +1,149 @@
+use common::{error::number, Answer, Day, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const INPUT: &str = include_str!("input");
+
+#[derive(Default)]
+pub struct Cubes {
+    red: u16,
+    green: u16,
+    blue: u16,
+}
+
+impl Cubes {
+    pub fn power(&self) -> u16 {
+        self.red * self.green * self.blue
+    }
+
+    pub fn max(&self, other: &Cubes) -> Cubes {
+        Cubes {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+}
+
+pub struct Game {
+    id: u16,
+    sets: Vec<Cubes>,
+}
+
+fn parse_set(y: usize, line: &str, set: &str) -> Result<Cubes, ParseError> {
+    let mut result: Cubes = Default::default();
+
+    for cubes in set.split(", ") {
+        let mut s = cubes.split_whitespace();
+        let n = number(y, line, s.next().unwrap_or(cubes))?;
+        match s.next() {
+            Some("red") => result.red = n,
+            Some("green") => result.green = n,
+            Some("blue") => result.blue = n,
+            Some(color) => {
+                return Err(ParseError::at(
+                    y,
+                    line,
+                    color,
+                    "expected red, green or blue",
+                ))
+            }
+            None => return Err(ParseError::at(y, line, cubes, "expected a color")),
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^Game (\d+): (.+)").unwrap();
+        }
+
+        input
+            .lines()
+            .zip(1..)
+            .map(|(line, y)| {
+                let (_, [id, sets]) = RE
+                    .captures(line)
+                    .ok_or_else(|| ParseError::at(y, line, line, "expected `Game <id>: <sets>`"))?
+                    .extract();
+
+                Ok(Game {
+                    id: number(y, line, id)?,
+                    sets: sets
+                        .split("; ")
+                        .map(|set| parse_set(y, line, set))
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u16> {
+        Ok(input
+            .iter()
+            .filter(|game| {
+                game.sets
+                    .iter()
+                    .all(|set| set.red <= 12 && set.green <= 13 && set.blue <= 14)
+            })
+            .map(|game| game.id)
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u16> {
+        Ok(input
+            .iter()
+            .map(|game| {
+                game.sets
+                    .iter()
+                    .fold(Cubes::default(), |acc, set| acc.max(set))
+                    .power()
+            })
+            .sum())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day2>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example-1");
+
+    #[test]
+    fn test_example1() {
+        assert_eq!(Day2::solve1(EXAMPLE1), Ok(8));
+    }
+
+    #[test]
+    fn test_example2() {
+        assert_eq!(Day2::solve2(EXAMPLE1), Ok(2286));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day2::solve1("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple\n").map(|_| ()),
+            Err(ParseError::new(2, 19, "purple", "expected red, green or blue").into())
+        );
+        assert_eq!(
+            Day2::parse("Game x: 3 blue\n")
+                .map(|_| ())
+                .unwrap_err()
+                .line,
+            1
+        );
+    }
+}
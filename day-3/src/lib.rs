@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use common::{Answer, Day, Grid, ParseError, Position, Solution};
+
+pub const INPUT: &str = include_str!("input");
+
+pub struct Number {
+    value: u32,
+    cells: Vec<Position>,
+}
+
+fn numbers(grid: &Grid<char>) -> Vec<Number> {
+    let mut numbers = Vec::new();
+
+    for pos in grid.positions() {
+        let c = grid[pos];
+
+        let Some(n) = c.to_digit(10) else {
+            continue;
+        };
+
+        let continues = pos.x > 0 && grid[pos - (1, 0).into()].is_ascii_digit();
+
+        match numbers.last_mut() {
+            Some(Number { value, cells }) if continues => {
+                *value = *value * 10 + n;
+                cells.push(pos);
+            }
+            _ => numbers.push(Number {
+                value: n,
+                cells: vec![pos],
+            }),
+        }
+    }
+
+    numbers
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, |c| c)?;
+        let numbers = numbers(&grid);
+
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u32> {
+        Ok(input
+            .numbers
+            .iter()
+            .filter(|number| {
+                number.cells.iter().any(|pos| {
+                    input
+                        .grid
+                        .neighbours8(*pos)
+                        .any(|(_, c)| *c != '.' && !c.is_ascii_digit())
+                })
+            })
+            .map(|number| number.value)
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u32> {
+        let mut parts: Grid<Option<usize>> = input.grid.map(|_| None);
+
+        for (i, number) in input.numbers.iter().enumerate() {
+            for pos in &number.cells {
+                parts[*pos] = Some(i);
+            }
+        }
+
+        Ok(input
+            .grid
+            .iter()
+            .filter(|(_, c)| **c == '*')
+            .filter_map(|(pos, _)| {
+                let found: HashSet<usize> = parts
+                    .neighbours8(pos)
+                    .filter_map(|(_, part)| *part)
+                    .collect();
+
+                if found.len() == 2 {
+                    Some(
+                        found
+                            .into_iter()
+                            .map(|i| input.numbers[i].value)
+                            .product::<u32>(),
+                    )
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day3>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_example1() {
+        assert_eq!(Day3::solve1(EXAMPLE), Ok(4361));
+    }
+
+    #[test]
+    fn test_example2() {
+        assert_eq!(Day3::solve2(EXAMPLE), Ok(467835));
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use common::{error::number, Answer, Day, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"^Card +\d+: ([^|]+)\|(.+)").unwrap();
+}
+
+pub const INPUT: &str = include_str!("input");
+
+#[derive(Clone)]
+pub struct Card {
+    count: u32,
+    matches: u16,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer1 = u16;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .zip(1..)
+            .map(|(line, y)| {
+                let (_, [winning, owned]) = RE
+                    .captures(line)
+                    .ok_or_else(|| {
+                        ParseError::at(y, line, line, "expected `Card <id>: <numbers> | <numbers>`")
+                    })?
+                    .extract();
+
+                let numbers = |s: &str| -> Result<HashSet<u16>, ParseError> {
+                    s.split_whitespace().map(|n| number(y, line, n)).collect()
+                };
+                let winning = numbers(winning)?;
+                let owned = numbers(owned)?;
+
+                let matches = owned.intersection(&winning).count() as u16;
+                Ok(Card { count: 1, matches })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u16> {
+        Ok(input
+            .iter()
+            .map(|card| {
+                if card.matches == 0 {
+                    0
+                } else {
+                    2_u16.pow(card.matches as u32 - 1)
+                }
+            })
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u32> {
+        let mut cards = input.clone();
+
+        for x1 in 0..cards.len() {
+            let card = cards.get(x1).cloned().unwrap();
+
+            for x2 in (x1 + 1)..(cards.len().min(x1 + 1 + card.matches as usize)) {
+                cards.get_mut(x2).unwrap().count += card.count;
+            }
+        }
+
+        Ok(cards.into_iter().fold(0, |acc, card| acc + card.count))
+    }
+}
+
+pub const DAY: Day = Day::new::<Day4>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(Day4::solve1(EXAMPLE), Ok(13));
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(Day4::solve2(EXAMPLE), Ok(30));
+    }
+}
@@ -0,0 +1,907 @@
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    fmt,
+};
+
+use common::{error::number, Answer, Day, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const INPUT: &str = include_str!("input");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: u128,
+    pub end: u128,
+}
+
+impl Interval {
+    pub fn new(start: u128, length: u128) -> Self {
+        Self {
+            start,
+            end: start + length,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let result = Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        };
+
+        (!result.is_empty()).then_some(result)
+    }
+}
+
+pub fn intersect(left: &[Interval], right: &[Interval]) -> Vec<Interval> {
+    merge(
+        left.iter()
+            .flat_map(|l| right.iter().filter_map(|r| l.intersect(r)))
+            .collect(),
+    )
+}
+
+pub fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort();
+
+    let mut result: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals.into_iter().filter(|i| !i.is_empty()) {
+        match result.last_mut() {
+            Some(last) if interval.start <= last.end => {
+                last.end = last.end.max(interval.end);
+            }
+            _ => result.push(interval),
+        }
+    }
+
+    result
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range {
+    source_range_start: u128,
+    destination_range_start: u128,
+    range_length: u128,
+}
+
+impl Range {
+    pub fn map(&self, n: u128) -> Option<u128> {
+        if self.source_range_start <= n && n < self.source_range_start + self.range_length {
+            Some(self.destination_range_start + (n - self.source_range_start))
+        } else {
+            None
+        }
+    }
+
+    pub fn unmap(&self, n: u128) -> Option<u128> {
+        self.invert().map(n)
+    }
+
+    pub fn invert(&self) -> Range {
+        Range {
+            source_range_start: self.destination_range_start,
+            destination_range_start: self.source_range_start,
+            range_length: self.range_length,
+        }
+    }
+
+    pub fn source(&self) -> Interval {
+        Interval::new(self.source_range_start, self.range_length)
+    }
+
+    // Splits `interval` at this range's boundaries into the mapped overlap
+    // and whatever lies outside of it, unmapped.
+    pub fn map_interval(&self, interval: Interval) -> (Option<Interval>, Vec<Interval>) {
+        let Some(overlap) = interval.intersect(&self.source()) else {
+            return (None, vec![interval]);
+        };
+
+        let rest = [
+            Interval {
+                start: interval.start,
+                end: overlap.start,
+            },
+            Interval {
+                start: overlap.end,
+                end: interval.end,
+            },
+        ]
+        .into_iter()
+        .filter(|i| !i.is_empty())
+        .collect();
+
+        let mapped = self
+            .map(overlap.start)
+            .zip(self.map(overlap.end - 1))
+            .map(|(start, last)| Interval {
+                start,
+                end: last + 1,
+            });
+
+        (mapped, rest)
+    }
+
+    pub fn unmap_interval(&self, interval: Interval) -> (Option<Interval>, Vec<Interval>) {
+        self.invert().map_interval(interval)
+    }
+
+    fn identity(interval: Interval) -> Range {
+        Range {
+            source_range_start: interval.start,
+            destination_range_start: interval.start,
+            range_length: interval.end - interval.start,
+        }
+    }
+
+    // The part of this range whose sources lie in `interval`, which has to be
+    // within them.
+    fn restrict(&self, interval: Interval) -> Range {
+        Range {
+            source_range_start: interval.start,
+            destination_range_start: self.destination_range_start
+                + (interval.start - self.source_range_start),
+            range_length: interval.end - interval.start,
+        }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.source_range_start == self.destination_range_start
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    source: String,
+    destination: String,
+    ranges: Vec<Range>,
+}
+
+impl Map {
+    // Where ranges overlap, the first one wins, as it does in `Almanac::map`,
+    // so each range is clipped to the sources no earlier range has claimed.
+    // Identity ranges claim sources too, and are only dropped afterwards.
+    pub fn new(source: &str, destination: &str, ranges: Vec<Range>) -> Self {
+        let mut claimed: Vec<Interval> = Vec::new();
+        let mut clipped = Vec::new();
+
+        for range in ranges {
+            let mut rest = vec![range.source()];
+            for taken in &claimed {
+                rest = rest
+                    .into_iter()
+                    .flat_map(|interval| Range::identity(*taken).map_interval(interval).1)
+                    .collect();
+            }
+
+            clipped.extend(rest.into_iter().map(|interval| range.restrict(interval)));
+            claimed.push(range.source());
+            claimed = merge(claimed);
+        }
+
+        let mut ranges: Vec<_> = clipped
+            .into_iter()
+            .filter(|range| range.range_length > 0 && !range.is_identity())
+            .collect();
+        ranges.sort_by_key(|range| range.source_range_start);
+
+        let ranges = ranges
+            .into_iter()
+            .fold(Vec::new(), |mut acc: Vec<Range>, range| {
+                match acc.last_mut() {
+                    Some(last)
+                        if last.source().end == range.source_range_start
+                            && last.invert().source().end == range.destination_range_start =>
+                    {
+                        last.range_length += range.range_length;
+                    }
+                    _ => acc.push(range),
+                }
+                acc
+            });
+
+        Self {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            ranges,
+        }
+    }
+
+    pub fn get(&self, n: u128) -> u128 {
+        let i = self
+            .ranges
+            .partition_point(|range| range.source_range_start <= n);
+
+        i.checked_sub(1)
+            .and_then(|i| self.ranges[i].map(n))
+            .unwrap_or(n)
+    }
+
+    fn bound(&self) -> u128 {
+        self.ranges
+            .iter()
+            .flat_map(|range| [range.source().end, range.invert().source().end])
+            .max()
+            .unwrap_or(0)
+    }
+
+    // All ranges up to `bound`, with the gaps between them filled in by
+    // identity ranges.
+    fn pieces(&self, bound: u128) -> Vec<Range> {
+        let mut pieces = Vec::new();
+        let mut start = 0;
+
+        for range in &self.ranges {
+            if start < range.source_range_start {
+                pieces.push(Range::identity(Interval {
+                    start,
+                    end: range.source_range_start,
+                }));
+            }
+            pieces.push(range.clone());
+            start = range.source().end;
+        }
+
+        if start < bound {
+            pieces.push(Range::identity(Interval { start, end: bound }));
+        }
+
+        pieces
+    }
+
+    // Composes `self` followed by `other` into a single map. Past the
+    // largest bound of either map, both are the identity.
+    pub fn then(&self, other: &Map) -> Map {
+        let bound = self.bound().max(other.bound());
+        let next = other.pieces(bound);
+        let mut ranges = Vec::new();
+
+        for piece in self.pieces(bound) {
+            let image = piece.invert().source();
+            let first = next.partition_point(|range| range.source().end <= image.start);
+
+            for range in next[first..]
+                .iter()
+                .take_while(|range| range.source_range_start < image.end)
+            {
+                let overlap = image.intersect(&range.source()).unwrap();
+
+                ranges.push(Range {
+                    source_range_start: piece.unmap(overlap.start).unwrap(),
+                    destination_range_start: range.map(overlap.start).unwrap(),
+                    range_length: overlap.end - overlap.start,
+                });
+            }
+        }
+
+        Map::new(&self.source, &other.destination, ranges)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.source, self.destination)?;
+
+        for range in &self.ranges {
+            writeln!(
+                f,
+                "{} {} {}",
+                range.destination_range_start, range.source_range_start, range.range_length
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChainError {
+    Unknown(String),
+    Broken {
+        source: String,
+        destination: String,
+        reachable: Vec<String>,
+    },
+    Ambiguous {
+        source: String,
+        destination: String,
+        paths: Vec<Vec<String>>,
+    },
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::Unknown(category) => write!(f, "unknown category {}", category),
+            ChainError::Broken {
+                source,
+                destination,
+                reachable,
+            } => write!(
+                f,
+                "no chain of maps from {} to {}, only reaching {}",
+                source,
+                destination,
+                reachable.join(", ")
+            ),
+            ChainError::Ambiguous {
+                source,
+                destination,
+                paths,
+            } => write!(
+                f,
+                "ambiguous chain of maps from {} to {}: {}",
+                source,
+                destination,
+                paths
+                    .iter()
+                    .map(|path| path.join("-to-"))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+// Why a question about the seeds couldn't be answered: either there's no chain
+// of maps from seed to location, or the seeds don't pair up into ranges.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AlmanacError {
+    Chain(ChainError),
+    UnpairedSeed(u128),
+}
+
+impl From<ChainError> for AlmanacError {
+    fn from(err: ChainError) -> Self {
+        AlmanacError::Chain(err)
+    }
+}
+
+impl fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlmanacError::Chain(err) => err.fmt(f),
+            AlmanacError::UnpairedSeed(seed) => {
+                write!(f, "seed {} has no range length to pair with", seed)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AlmanacError {}
+
+#[derive(Default, Debug)]
+pub struct Almanac {
+    seeds: Vec<u128>,
+    maps: HashMap<(String, String), Vec<Range>>,
+}
+
+impl Almanac {
+    // The ranges of a single map. Only used on steps of a `chain`, which has
+    // already checked that the map exists.
+    fn ranges(&self, source: &str, destination: &str) -> &[Range] {
+        &self.maps[&(source.to_string(), destination.to_string())]
+    }
+
+    fn map(&self, source: &str, destination: &str, intervals: Vec<Interval>) -> Vec<Interval> {
+        let ranges = self.ranges(source, destination);
+
+        let mut pending = intervals;
+        let mut mapped = Vec::new();
+
+        for range in ranges {
+            let mut rest = Vec::new();
+
+            for interval in pending {
+                let (hit, miss) = range.map_interval(interval);
+                mapped.extend(hit);
+                rest.extend(miss);
+            }
+
+            pending = rest;
+        }
+
+        mapped.extend(pending);
+        merge(mapped)
+    }
+
+    // The seeds one by one, or as pairs of start and length with `groups`,
+    // which needs an even number of them.
+    pub fn seeds(&self, groups: bool) -> Result<Vec<Interval>, AlmanacError> {
+        let seeds = if groups {
+            let pairs = self.seeds.chunks_exact(2);
+            if let [seed] = pairs.remainder() {
+                return Err(AlmanacError::UnpairedSeed(*seed));
+            }
+            pairs.map(|pair| Interval::new(pair[0], pair[1])).collect()
+        } else {
+            self.seeds
+                .iter()
+                .map(|seed| Interval::new(*seed, 1))
+                .collect()
+        };
+
+        Ok(merge(seeds))
+    }
+
+    // Every destination value has itself as a preimage unless some range
+    // claims it as a source, plus one preimage per range covering it.
+    fn unmap(&self, source: &str, destination: &str, intervals: Vec<Interval>) -> Vec<Interval> {
+        let ranges = self.ranges(source, destination);
+
+        let mut identity = intervals.clone();
+        let mut unmapped = Vec::new();
+
+        for range in ranges {
+            identity = identity
+                .into_iter()
+                .flat_map(|interval| range.map_interval(interval).1)
+                .collect();
+            unmapped.extend(
+                intervals
+                    .iter()
+                    .filter_map(|interval| range.unmap_interval(*interval).0),
+            );
+        }
+
+        unmapped.extend(identity);
+        merge(unmapped)
+    }
+
+    fn graph(&self) -> HashMap<&str, Vec<&str>> {
+        let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        for (source, destination) in self.maps.keys() {
+            graph.entry(source).or_default().push(destination);
+            graph.entry(destination).or_default();
+        }
+
+        for destinations in graph.values_mut() {
+            destinations.sort();
+        }
+
+        graph
+    }
+
+    pub fn chain(&self, source: &str, destination: &str) -> Result<Vec<String>, ChainError> {
+        let graph = self.graph();
+
+        for category in [source, destination] {
+            if !graph.contains_key(category) {
+                return Err(ChainError::Unknown(category.to_string()));
+            }
+        }
+
+        let mut paths = Vec::new();
+        let mut reachable = BTreeSet::new();
+        let mut stack = vec![vec![source]];
+
+        while let Some(path) = stack.pop() {
+            let last = *path.last().unwrap();
+            reachable.insert(last);
+
+            if last == destination {
+                paths.push(path.iter().map(|s| s.to_string()).collect());
+                continue;
+            }
+
+            for next in graph.get(last).unwrap().iter().rev() {
+                if !path.contains(next) {
+                    let mut path = path.clone();
+                    path.push(next);
+                    stack.push(path);
+                }
+            }
+        }
+
+        match paths.len() {
+            0 => Err(ChainError::Broken {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                reachable: reachable.into_iter().map(|s| s.to_string()).collect(),
+            }),
+            1 => Ok(paths.pop().unwrap()),
+            _ => Err(ChainError::Ambiguous {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                paths,
+            }),
+        }
+    }
+
+    pub fn convert(
+        &self,
+        source: &str,
+        destination: &str,
+        intervals: Vec<Interval>,
+    ) -> Result<Vec<Interval>, ChainError> {
+        let chain = self.chain(source, destination)?;
+
+        Ok(chain.windows(2).fold(merge(intervals), |intervals, step| {
+            self.map(&step[0], &step[1], intervals)
+        }))
+    }
+
+    pub fn compose(&self, source: &str, destination: &str) -> Result<Map, ChainError> {
+        let chain = self.chain(source, destination)?;
+
+        Ok(chain
+            .windows(2)
+            .map(|step| {
+                let ranges = self.ranges(&step[0], &step[1]);
+                Map::new(&step[0], &step[1], ranges.to_vec())
+            })
+            .reduce(|composed, map| composed.then(&map))
+            .unwrap_or_else(|| Map::new(source, destination, Vec::new())))
+    }
+
+    pub fn preimage(
+        &self,
+        source: &str,
+        destination: &str,
+        intervals: Vec<Interval>,
+    ) -> Result<Vec<Interval>, ChainError> {
+        let chain = self.chain(source, destination)?;
+
+        Ok(chain
+            .windows(2)
+            .rev()
+            .fold(merge(intervals), |intervals, step| {
+                self.unmap(&step[0], &step[1], intervals)
+            }))
+    }
+
+    pub fn seeds_at(
+        &self,
+        location: Interval,
+        groups: bool,
+    ) -> Result<Vec<Interval>, AlmanacError> {
+        let candidates = self.preimage("seed", "location", vec![location])?;
+        Ok(intersect(&candidates, &self.seeds(groups)?))
+    }
+
+    // Binary searches the location axis for the smallest prefix whose
+    // preimage still contains a seed, independently of `convert`.
+    pub fn lowest_location(&self, groups: bool) -> Result<Option<u128>, AlmanacError> {
+        let seeds = self.seeds(groups)?;
+        let bound = self
+            .maps
+            .values()
+            .flatten()
+            .flat_map(|range| [range.source().end, range.invert().source().end])
+            .chain(seeds.iter().map(|seed| seed.end))
+            .max()
+            .unwrap_or(0);
+
+        let reachable = |end: u128| -> Result<bool, ChainError> {
+            let candidates = self.preimage("seed", "location", vec![Interval { start: 0, end }])?;
+            Ok(!intersect(&candidates, &seeds).is_empty())
+        };
+
+        if !reachable(bound)? {
+            return Ok(None);
+        }
+
+        let (mut low, mut high) = (0, bound);
+        while low + 1 < high {
+            let middle = low + (high - low) / 2;
+            if reachable(middle)? {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        Ok(Some(high - 1))
+    }
+
+    // The lowest location of any seed, or None if there are no seeds.
+    pub fn closest(&self, groups: bool) -> Result<Option<u128>, AlmanacError> {
+        let location = self.convert("seed", "location", self.seeds(groups)?)?;
+        Ok(location.first().map(|interval| interval.start))
+    }
+}
+
+type Lines<'a> = VecDeque<(usize, &'a str)>;
+
+fn parse_seeds(lines: &mut Lines, result: &mut Almanac) -> Result<bool, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^seeds: (.+)").unwrap();
+    }
+
+    let (y, line) = *lines.front().unwrap();
+    let seeds = match RE.captures(line) {
+        Some(captures) => captures.get(1).unwrap(),
+        None => return Ok(false),
+    };
+
+    lines.pop_front();
+
+    result.seeds = seeds
+        .as_str()
+        .split_whitespace()
+        .map(|s| number(y, line, s))
+        .collect::<Result<_, _>>()?;
+
+    Ok(true)
+}
+
+fn parse_map(lines: &mut Lines, almanac: &mut Almanac) -> Result<bool, ParseError> {
+    lazy_static! {
+        static ref RE_MAP: Regex = Regex::new(r"^([^-]+)-to-([^ ]+) map:").unwrap();
+        static ref RE_RANGE: Regex = Regex::new(r"^(\d+) (\d+) (\d+)$").unwrap();
+    }
+
+    let (y, line) = *lines.front().unwrap();
+    let (_, [source, destination]) = match RE_MAP.captures(line) {
+        Some(captures) => captures.extract(),
+        None => return Ok(false),
+    };
+
+    let key = (source.to_string(), destination.to_string());
+    if almanac.maps.contains_key(&key) {
+        return Err(ParseError::at(y, line, line, "duplicate map"));
+    }
+
+    let mut ranges = Vec::new();
+
+    lines.pop_front();
+
+    while let Some(&(y, line)) = lines.front() {
+        let Some(captures) = RE_RANGE.captures(line) else {
+            break;
+        };
+
+        lines.pop_front();
+        let (_, [destination, source, length]) = captures.extract();
+        ranges.push(Range {
+            destination_range_start: number(y, line, destination)?,
+            source_range_start: number(y, line, source)?,
+            range_length: number(y, line, length)?,
+        })
+    }
+
+    almanac.maps.insert(key, ranges);
+
+    Ok(true)
+}
+
+pub fn parse(s: &str) -> Result<Almanac, ParseError> {
+    let mut almanac: Almanac = Default::default();
+    let mut lines: Lines = (1..).zip(s.lines()).collect();
+
+    while let Some(&(y, line)) = lines.front() {
+        if line.trim().is_empty() {
+            lines.pop_front();
+            continue;
+        }
+
+        if parse_seeds(&mut lines, &mut almanac)? {
+            continue;
+        }
+
+        if parse_map(&mut lines, &mut almanac)? {
+            continue;
+        }
+
+        return Err(ParseError::at(
+            y,
+            line,
+            line,
+            "expected seeds, a map header or a range",
+        ));
+    }
+
+    Ok(almanac)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u128> {
+        Ok(input.closest(false)?.ok_or("no seeds to locate")?)
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u128> {
+        Ok(input.closest(true)?.ok_or("no seeds to locate")?)
+    }
+}
+
+pub const DAY: Day = Day::new::<Day5>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(Day5::solve1(EXAMPLE), Ok(35));
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(Day5::solve2(EXAMPLE), Ok(46));
+        assert_eq!(
+            Day5::solve2("seeds: 79 0\n\nseed-to-location map:\n0 0 100\n")
+                .map_err(|err| err.to_string()),
+            Err("no seeds to locate".to_string())
+        );
+    }
+
+    #[test]
+    fn test_map_interval() {
+        let range = Range {
+            source_range_start: 98,
+            destination_range_start: 50,
+            range_length: 2,
+        };
+
+        assert_eq!(
+            range.map_interval(Interval::new(90, 20)),
+            (
+                Some(Interval::new(50, 2)),
+                vec![Interval::new(90, 8), Interval::new(100, 10)]
+            )
+        );
+        assert_eq!(
+            range.map_interval(Interval::new(0, 10)),
+            (None, vec![Interval::new(0, 10)])
+        );
+    }
+
+    #[test]
+    fn test_chain() {
+        let almanac = parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            almanac.chain("soil", "light"),
+            Ok(vec![
+                "soil".to_string(),
+                "fertilizer".to_string(),
+                "water".to_string(),
+                "light".to_string()
+            ])
+        );
+        assert_eq!(
+            almanac.chain("location", "seed"),
+            Err(ChainError::Broken {
+                source: "location".to_string(),
+                destination: "seed".to_string(),
+                reachable: vec!["location".to_string()]
+            })
+        );
+        assert_eq!(
+            almanac.chain("seed", "moon"),
+            Err(ChainError::Unknown("moon".to_string()))
+        );
+
+        let almanac =
+            parse("seed-to-soil map:\n\nsoil-to-water map:\n\nseed-to-water map:\n").unwrap();
+        assert!(matches!(
+            almanac.chain("seed", "water"),
+            Err(ChainError::Ambiguous { paths, .. }) if paths.len() == 2
+        ));
+
+        // The solver reports a broken chain rather than panicking on it.
+        assert_eq!(
+            Day5::solve1("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n")
+                .map_err(|err| err.to_string()),
+            Err(ChainError::Unknown("location".to_string()).to_string())
+        );
+    }
+
+    #[test]
+    fn test_preimage() {
+        let almanac = parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            almanac.preimage("seed", "soil", vec![Interval::new(50, 3)]),
+            Ok(vec![Interval::new(50, 1), Interval::new(98, 2)])
+        );
+        assert_eq!(
+            almanac.seeds_at(Interval::new(46, 1), true),
+            Ok(vec![Interval::new(82, 1)])
+        );
+        assert_eq!(almanac.seeds_at(Interval::new(0, 46), true), Ok(vec![]));
+        assert_eq!(
+            almanac.seeds_at(Interval::new(35, 1), false),
+            Ok(vec![Interval::new(13, 1)])
+        );
+        assert_eq!(almanac.lowest_location(false), Ok(Some(35)));
+        assert_eq!(almanac.lowest_location(true), Ok(Some(46)));
+        assert_eq!(
+            almanac.unmap("seed", "soil", vec![Interval::new(98, 1)]),
+            vec![Interval::new(96, 1)]
+        );
+        assert_eq!(
+            Range {
+                source_range_start: 98,
+                destination_range_start: 50,
+                range_length: 2,
+            }
+            .unmap(51),
+            Some(99)
+        );
+    }
+
+    #[test]
+    fn test_compose() {
+        let almanac = parse(EXAMPLE).unwrap();
+        let map = almanac.compose("seed", "location").unwrap();
+
+        assert_eq!([79, 14, 55, 13].map(|seed| map.get(seed)), [82, 43, 86, 35]);
+
+        let printed = map.to_string();
+        assert!(printed.starts_with("seed-to-location map:\n"));
+
+        let reparsed = parse(&printed)
+            .unwrap()
+            .compose("seed", "location")
+            .unwrap();
+        assert_eq!(reparsed, map);
+        assert!((0..120).all(|n| {
+            let expected = almanac.convert("seed", "location", vec![Interval::new(n, 1)]);
+            expected == Ok(vec![Interval::new(map.get(n), 1)]) && reparsed.get(n) == map.get(n)
+        }));
+
+        // Overlapping ranges, where the first one wins even if it's the
+        // identity.
+        let almanac = parse(
+            "seed-to-soil map:\n50 10 10\n0 5 10\n10 10 5\n\n\
+             soil-to-location map:\n30 30 10\n0 35 10\n",
+        )
+        .unwrap();
+        let map = almanac.compose("seed", "location").unwrap();
+        assert!((0..80).all(|n| {
+            let expected = almanac.convert("seed", "location", vec![Interval::new(n, 1)]);
+            expected == Ok(vec![Interval::new(map.get(n), 1)])
+        }));
+        assert_eq!(map.get(12), 52);
+        assert_eq!(map.get(37), 37);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n").map(|_| ()),
+            Err(ParseError::new(
+                5,
+                1,
+                "52 50",
+                "expected seeds, a map header or a range"
+            ))
+        );
+        assert_eq!(
+            parse("seeds: 79 1x4\n").map(|_| ()),
+            Err(ParseError::new(1, 11, "1x4", "expected a number"))
+        );
+    }
+
+    #[test]
+    fn test_unpaired_seeds() {
+        // Only part 2 reads the seeds as pairs.
+        let input = "seeds: 79 14 55\n\nseed-to-location map:\n0 50 100\n";
+        assert_eq!(Day5::solve1(input), Ok(5));
+        assert_eq!(
+            Day5::solve2(input).map_err(|err| err.to_string()),
+            Err("seed 55 has no range length to pair with".to_string())
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use std::{
+    fmt::{Debug, Display},
+    io::{self, Write},
+    iter,
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use common::{error::number, Answer, Day, ParseError, Solution};
+use num::{integer::Roots, BigUint, CheckedMul, Integer, Unsigned};
+
+pub const INPUT: &str = include_str!("input");
+
+pub trait Value:
+    Integer + Unsigned + Roots + CheckedMul + Clone + Debug + Display + FromStr
+{
+}
+
+impl<T> Value for T where
+    T: Integer + Unsigned + Roots + CheckedMul + Clone + Debug + Display + FromStr
+{
+}
+
+fn two<T: Value>() -> T {
+    T::one() + T::one()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Strategy<T> {
+    pub hold: T,
+    pub distance: T,
+    // None when even the best hold does not beat the record.
+    pub margin: Option<T>,
+}
+
+#[derive(Debug)]
+pub struct Race<T = u64> {
+    time: T,
+    record: T,
+}
+
+impl<T: Value> Race<T> {
+    pub fn new(time: T, record: T) -> Self {
+        Self { time, record }
+    }
+
+    // None when the distance does not fit in T.
+    pub fn distance(&self, hold: &T) -> Option<T> {
+        if *hold > self.time {
+            return Some(T::zero());
+        }
+
+        hold.checked_mul(&(self.time.clone() - hold.clone()))
+    }
+
+    // A distance too large for T is necessarily larger than the record.
+    pub fn beats(&self, hold: &T) -> bool {
+        self.distance(hold)
+            .is_none_or(|distance| distance > self.record)
+    }
+
+    // The hold times h with h * (time - h) > record lie strictly between the
+    // roots of h^2 - time * h + record. The integer square root only gets us
+    // within one step of the first winning h, so it is adjusted until exact.
+    // When time^2 does not fit in T the first winning h is binary searched.
+    fn first_guess(&self) -> Option<T> {
+        let square = self.time.checked_mul(&self.time)?;
+        let record = self.record.checked_mul(&(two::<T>() * two()))?;
+        let discriminant = square - record;
+
+        Some((self.time.clone() - discriminant.sqrt()) / two())
+    }
+
+    fn first_search(&self) -> T {
+        let (mut low, mut high) = (T::zero(), self.time.clone() / two());
+        while low < high {
+            let middle = low.clone() + (high.clone() - low.clone()) / two();
+            if self.beats(&middle) {
+                high = middle;
+            } else {
+                low = middle + T::one();
+            }
+        }
+
+        low
+    }
+
+    pub fn winning(&self) -> Option<RangeInclusive<T>> {
+        let half = self.time.clone() / two();
+        if !self.beats(&half) {
+            return None;
+        }
+
+        let mut low = self.first_guess().unwrap_or_else(|| self.first_search());
+        while !low.is_zero() && self.beats(&(low.clone() - T::one())) {
+            low = low - T::one();
+        }
+        while !self.beats(&low) {
+            low = low + T::one();
+        }
+
+        let high = self.time.clone() - low.clone();
+        Some(low..=high)
+    }
+
+    // Holding for time - hold is just as good, so this is the earlier of the
+    // (at most two) optimal holds. None when the best distance overflows T.
+    pub fn strategy(&self) -> Option<Strategy<T>> {
+        let hold = self.time.clone() / two();
+        let distance = self.distance(&hold)?;
+        let margin = (distance > self.record).then(|| distance.clone() - self.record.clone());
+
+        Some(Strategy {
+            hold,
+            distance,
+            margin,
+        })
+    }
+
+    pub fn sweep(&self) -> impl Iterator<Item = (T, Option<T>)> + '_ {
+        iter::successors(Some(T::zero()), |hold| {
+            (*hold < self.time).then(|| hold.clone() + T::one())
+        })
+        .map(|hold| {
+            let distance = self.distance(&hold);
+            (hold, distance)
+        })
+    }
+
+    // Distances that overflow T are left empty.
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "hold,distance,wins")?;
+        for (hold, distance) in self.sweep() {
+            let wins = self.beats(&hold);
+            match distance {
+                Some(distance) => writeln!(out, "{},{},{}", hold, distance, wins)?,
+                None => writeln!(out, "{},,{}", hold, wins)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn win(&self) -> T {
+        self.winning().map_or(T::zero(), |range| {
+            range.end().clone() - range.start().clone() + T::one()
+        })
+    }
+}
+
+struct Sheet<'a> {
+    times: (usize, &'a str, Vec<&'a str>),
+    records: (usize, &'a str, Vec<&'a str>),
+}
+
+fn parse_row<'a>(
+    lines: &mut impl Iterator<Item = (&'a str, usize)>,
+    expected: usize,
+    header: &str,
+) -> Result<(usize, &'a str, Vec<&'a str>), ParseError> {
+    let (line, y) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(expected, 1, "", format!("missing `{}` line", header)))?;
+    let mut fields = line.split_whitespace();
+
+    match fields.next() {
+        Some(field) if field == header => Ok((y, line, fields.collect())),
+        Some(field) => Err(ParseError::at(
+            y,
+            line,
+            field,
+            format!("expected `{}`", header),
+        )),
+        None => Err(ParseError::new(
+            y,
+            1,
+            line,
+            format!("expected `{}`", header),
+        )),
+    }
+}
+
+fn parse_sheet(s: &str) -> Result<Sheet<'_>, ParseError> {
+    let mut lines = s.lines().zip(1..);
+    let times = parse_row(&mut lines, 1, "Time:")?;
+    let records = parse_row(&mut lines, 2, "Distance:")?;
+
+    if times.2.len() != records.2.len() {
+        return Err(ParseError::new(
+            records.0,
+            1,
+            records.1,
+            format!("expected {} distances", times.2.len()),
+        ));
+    }
+
+    Ok(Sheet { times, records })
+}
+
+fn parse1(sheet: &Sheet) -> Result<Vec<Race>, ParseError> {
+    let (time_y, time_line, times) = &sheet.times;
+    let (record_y, record_line, records) = &sheet.records;
+
+    times
+        .iter()
+        .zip(records)
+        .map(|(time, record)| {
+            Ok(Race {
+                time: number(*time_y, time_line, time)?,
+                record: number(*record_y, record_line, record)?,
+            })
+        })
+        .collect()
+}
+
+fn parse2(sheet: &Sheet) -> Result<Race<BigUint>, ParseError> {
+    let (time_y, time_line, times) = &sheet.times;
+    let (record_y, record_line, records) = &sheet.records;
+
+    Ok(Race {
+        time: number(*time_y, time_line, &times.join(""))?,
+        record: number(*record_y, record_line, &records.join(""))?,
+    })
+}
+
+pub struct Races {
+    races: Vec<Race>,
+    combined: Race<BigUint>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Answer1 = BigUint;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let sheet = parse_sheet(input)?;
+
+        Ok(Races {
+            races: parse1(&sheet)?,
+            combined: parse2(&sheet)?,
+        })
+    }
+
+    // Each race's count fits in a u64, but their product might not.
+    fn part1(input: &Self::Input) -> Answer<BigUint> {
+        Ok(input
+            .races
+            .iter()
+            .map(|race| BigUint::from(race.win()))
+            .product())
+    }
+
+    fn part2(input: &Self::Input) -> Answer<BigUint> {
+        Ok(input.combined.win())
+    }
+}
+
+pub const DAY: Day = Day::new::<Day6>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(Day6::solve1(EXAMPLE), Ok(BigUint::from(288u32)));
+        assert_eq!(
+            Day6::solve1("Time: 4194305 4194305 4194305\nDistance: 0 0 0\n"),
+            Ok(BigUint::from(1u32) << 66)
+        );
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(Day6::solve2(EXAMPLE), Ok(BigUint::from(71503u32)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day6::solve1("Time: 7 15\n").map(|_| ()),
+            Err(ParseError::new(2, 1, "", "missing `Distance:` line").into())
+        );
+        assert_eq!(
+            Day6::solve1("Time: 7 15\nDistance: 9 4O\n").map(|_| ()),
+            Err(ParseError::new(2, 13, "4O", "expected a number").into())
+        );
+    }
+
+    #[test]
+    fn test_winning() {
+        let race = Race::<u64>::new;
+        assert_eq!(race(7, 9).winning(), Some(2..=5));
+        assert_eq!(race(30, 200).winning(), Some(11..=19));
+        assert_eq!(race(10, 25).winning(), None);
+        assert_eq!(race(10, 24).winning(), Some(5..=5));
+        assert_eq!(race(0, 0).winning(), None);
+
+        for time in 0..40 {
+            for record in 0..time * time / 4 + 2 {
+                let expected = (0..=time).filter(|h| h * (time - h) > record);
+                assert_eq!(race(time, record).win(), expected.count() as u64);
+            }
+        }
+
+        let max = u64::MAX;
+        assert_eq!(race(max, 0).winning(), Some(1..=max - 1));
+        assert_eq!(race(max, max).winning(), Some(2..=max - 2));
+
+        let time = 2 * u32::MAX as u64;
+        let best = race(time, 0).distance(&(time / 2)).unwrap();
+        assert_eq!(race(time, best - 1).winning(), Some(time / 2..=time / 2));
+        assert_eq!(race(time, best).winning(), None);
+    }
+
+    #[test]
+    fn test_big() {
+        assert_eq!(Race::<u8>::new(255, 0).distance(&100), None);
+        assert_eq!(Race::<u8>::new(255, 250).win(), 254);
+
+        let (time, record) = (10u128.pow(30), 10u128.pow(38) + 7);
+        let big = Race::new(BigUint::from(time), BigUint::from(record));
+        assert_eq!(big.win(), BigUint::from(Race::new(time, record).win()));
+
+        let sheet = "Time: 71530 71530 71530 71530\nDistance: 940200 940200 940200 940200\n";
+        let time = "71530".repeat(4).parse::<BigUint>().unwrap();
+        let record = "940200".repeat(4).parse::<BigUint>().unwrap();
+        assert_eq!(Day6::solve2(sheet), Ok(Race::new(time, record).win()));
+    }
+
+    #[test]
+    fn test_strategy() {
+        let race = Race::<u64>::new;
+        assert_eq!(
+            race(7, 9).strategy(),
+            Some(Strategy {
+                hold: 3,
+                distance: 12,
+                margin: Some(3)
+            })
+        );
+        assert_eq!(race(10, 24).strategy().unwrap().margin, Some(1));
+        assert_eq!(race(10, 25).strategy().unwrap().margin, None);
+        assert_eq!(Race::<u8>::new(40, 0).strategy(), None);
+
+        let mut csv = Vec::new();
+        race(3, 1).write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "hold,distance,wins\n0,0,false\n1,2,true\n2,2,true\n3,0,false\n"
+        );
+
+        let mut csv = Vec::new();
+        Race::<u8>::new(40, 0).write_csv(&mut csv).unwrap();
+        assert!(String::from_utf8(csv).unwrap().contains("\n20,,true\n"));
+    }
+}
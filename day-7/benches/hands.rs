@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day_7::{parse_hands, Card, Hand, HandKind, JokerCard, Positional, TieBreak};
+
+const HANDS: usize = 1_000_000;
+
+// A million random hands with bids, from a fixed xorshift seed.
+fn generate() -> String {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..HANDS)
+        .map(|_| {
+            let hand: String = (0..5)
+                .map(|_| b"23456789TJQKA"[next() as usize % 13] as char)
+                .collect();
+            format!("{} {}\n", hand, next() % 1000 + 1)
+        })
+        .collect()
+}
+
+// How hands used to be classified: count the cards in a fresh HashMap and add
+// the wildcards to the biggest count. Descending counts compare like kinds.
+fn hashed(cards: &[JokerCard]) -> Vec<usize> {
+    let mut wildcards = 0;
+    let counts = cards.iter().fold(HashMap::new(), |mut acc, card| {
+        if card.is_wild() {
+            wildcards += 1;
+        } else {
+            *acc.entry(card).or_insert(0) += 1;
+        }
+        acc
+    });
+
+    let mut counts: Vec<usize> = counts.into_values().collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    match counts.first_mut() {
+        Some(count) => *count += wildcards,
+        None => counts.push(wildcards),
+    }
+    counts
+}
+
+// The old ordering: a HashMap per hand on every comparison.
+fn hashmap(hands: &mut [(Hand<JokerCard>, u32)]) {
+    hands.sort_unstable_by(|(this, _), (other, _)| {
+        hashed(this.cards())
+            .cmp(&hashed(other.cards()))
+            .then_with(|| this.cards().cmp(other.cards()))
+    });
+}
+
+// Without the cached kind and key, but with the allocation-free classification,
+// to separate what the caching saves from what dropping the HashMap does.
+fn reclassify(hands: &mut [(Hand<JokerCard>, u32)]) {
+    hands.sort_unstable_by(|(this, _), (other, _)| {
+        HandKind::classify(this.cards())
+            .cmp(&HandKind::classify(other.cards()))
+            .then_with(|| this.cards().cmp(other.cards()))
+    });
+}
+
+fn hands(c: &mut Criterion) {
+    let hands = parse_hands::<JokerCard, 5>(&generate()).unwrap();
+
+    let mut group = c.benchmark_group("day-7 million hands");
+    group.sample_size(10);
+
+    group.bench_function("cached", |b| {
+        b.iter_batched_ref(
+            || hands.clone(),
+            |hands| Positional::sort(hands),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("reclassify", |b| {
+        b.iter_batched_ref(
+            || hands.clone(),
+            |hands| reclassify(hands),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("hashmap", |b| {
+        b.iter_batched_ref(
+            || hands.clone(),
+            |hands| hashmap(hands),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, hands);
+criterion_main!(benches);
@@ -0,0 +1,775 @@
+use std::cmp::{Ordering, Reverse};
+
+use common::{error::number, Answer, Day, ParseError, Solution};
+
+pub const INPUT: &str = include_str!("input");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NormalCard {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JokerCard {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for NormalCard {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            'A' => Self::Ace,
+            'K' => Self::King,
+            'Q' => Self::Queen,
+            'J' => Self::Jack,
+            'T' => Self::Ten,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(c),
+        })
+    }
+}
+
+impl TryFrom<char> for JokerCard {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            'A' => Self::Ace,
+            'K' => Self::King,
+            'Q' => Self::Queen,
+            'J' => Self::Joker,
+            'T' => Self::Ten,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(c),
+        })
+    }
+}
+
+impl From<NormalCard> for char {
+    fn from(card: NormalCard) -> Self {
+        b"23456789TJQKA"[card as usize] as char
+    }
+}
+
+impl From<JokerCard> for char {
+    fn from(card: JokerCard) -> Self {
+        b"J23456789TQKA"[card as usize] as char
+    }
+}
+
+// The bucket sizes of a hand, largest first, after the wildcards have joined
+// the largest bucket. Comparing these lexicographically gives the usual
+// ordering: [5] > [4, 1] > [3, 2] > [3, 1, 1] > [2, 2, 1] > ... for any size.
+// They are packed a nibble each, largest in the top nibble, so the comparison
+// is a single integer one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandKind(u64);
+
+impl HandKind {
+    pub fn classify<T: Card>(cards: &[T]) -> Self {
+        let mut counts = [0u8; 16];
+        let mut jokers = 0;
+        for card in cards {
+            if card.is_wild() {
+                jokers += 1;
+            } else {
+                counts[card.rank() as usize] += 1;
+            }
+        }
+
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts[0] += jokers;
+
+        HandKind(counts.iter().fold(0, |acc, &count| acc << 4 | count as u64))
+    }
+
+    pub fn buckets(&self) -> Vec<usize> {
+        (0..16)
+            .map(|i| (self.0 >> (60 - 4 * i) & 0xf) as usize)
+            .take_while(|&count| count > 0)
+            .collect()
+    }
+}
+
+impl std::fmt::Display for HandKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let buckets = self.buckets();
+        let name = match buckets[..] {
+            [5] => "five of a kind",
+            [4, 1] => "four of a kind",
+            [3, 2] => "full house",
+            [3, 1, 1] => "three of a kind",
+            [2, 2, 1] => "two pair",
+            [2, 1, 1, 1] => "one pair",
+            [1, 1, 1, 1, 1] => "high card",
+            _ => {
+                let buckets: Vec<_> = buckets.iter().map(usize::to_string).collect();
+                return f.pad(&buckets.join("-"));
+            }
+        };
+
+        f.pad(name)
+    }
+}
+
+pub trait Card:
+    TryFrom<char, Error = char>
+    + Into<char>
+    + std::fmt::Debug
+    + std::hash::Hash
+    + Clone
+    + Copy
+    + PartialEq
+    + Eq
+    + PartialOrd
+    + Ord
+    + 'static
+{
+    const WILDCARDS: &'static [Self] = &[];
+
+    // The position of the card in its `Ord` order, below 16.
+    fn rank(&self) -> u8;
+
+    fn is_wild(&self) -> bool {
+        Self::WILDCARDS.contains(self)
+    }
+}
+
+impl Card for NormalCard {
+    fn rank(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl Card for JokerCard {
+    const WILDCARDS: &'static [Self] = &[JokerCard::Joker];
+
+    fn rank(&self) -> u8 {
+        *self as u8
+    }
+}
+
+// The kind and the positional sort key (the kind followed by the rank of each
+// card, a nibble each) are worked out once, so sorting doesn't reclassify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hand<T: Card, const N: usize = 5> {
+    cards: [T; N],
+    kind: HandKind,
+    key: u128,
+}
+
+// How hands of the same kind are ordered.
+pub trait TieBreak<T: Card>: Sized {
+    fn cmp<const N: usize>(this: &[T; N], other: &[T; N]) -> Ordering;
+
+    fn sort<const N: usize>(hands: &mut [(Hand<T, N>, u32)]) {
+        hands.sort_unstable_by(|(this, _), (other, _)| this.cmp_by::<Self>(other));
+    }
+}
+
+// Camel cards: the first differing card, in the order the hand was dealt.
+pub struct Positional;
+
+impl<T: Card> TieBreak<T> for Positional {
+    fn cmp<const N: usize>(this: &[T; N], other: &[T; N]) -> Ordering {
+        this.cmp(other)
+    }
+
+    fn sort<const N: usize>(hands: &mut [(Hand<T, N>, u32)]) {
+        hands.sort_unstable_by_key(|(hand, _)| hand.key);
+    }
+}
+
+// Poker: the biggest buckets first, ranking each bucket by its card, so that
+// 33AA2 beats AA224 because its second pair is higher.
+pub struct Poker;
+
+fn poker_order<T: Card, const N: usize>(cards: &[T; N]) -> [T; N] {
+    let mut sorted = *cards;
+    sorted.sort_by_key(|card| {
+        let count = cards.iter().filter(|other| *other == card).count();
+        Reverse((count, *card))
+    });
+    sorted
+}
+
+impl<T: Card> TieBreak<T> for Poker {
+    fn cmp<const N: usize>(this: &[T; N], other: &[T; N]) -> Ordering {
+        poker_order(this).cmp(&poker_order(other))
+    }
+}
+
+// Poker, falling back to the suits of the cards in poker order when the ranks
+// are all the same. Cards of the same rank are ordered by suit first.
+pub struct SuitAware;
+
+pub trait Suited: Card {
+    fn suit(&self) -> Suit;
+}
+
+impl<T: Suited> TieBreak<T> for SuitAware {
+    fn cmp<const N: usize>(this: &[T; N], other: &[T; N]) -> Ordering {
+        let by_suit = |cards: &[T; N]| {
+            let mut cards = *cards;
+            cards.sort_unstable_by_key(|card| Reverse(card.suit()));
+            poker_order(&cards)
+        };
+        let (this, other) = (by_suit(this), by_suit(other));
+        this.cmp(&other).then_with(|| {
+            let suits = |cards: &[T; N]| cards.map(|card| card.suit());
+            suits(&this).cmp(&suits(&other))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+// A playing card, parsed from the Unicode playing cards block (U+1F0A1 is the
+// ace of spades). Cards compare by rank only.
+#[derive(Debug, Clone, Copy)]
+pub struct PlayingCard {
+    pub rank: NormalCard,
+    pub suit: Suit,
+}
+
+impl PartialEq for PlayingCard {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank
+    }
+}
+
+impl Eq for PlayingCard {}
+
+impl PartialOrd for PlayingCard {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PlayingCard {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+impl std::hash::Hash for PlayingCard {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.rank.hash(state);
+    }
+}
+
+impl TryFrom<char> for PlayingCard {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let suit = match c as u32 & !0xf {
+            0x1f0a0 => Suit::Spades,
+            0x1f0b0 => Suit::Hearts,
+            0x1f0c0 => Suit::Diamonds,
+            0x1f0d0 => Suit::Clubs,
+            _ => return Err(c),
+        };
+        let rank = match c as u32 & 0xf {
+            1 => NormalCard::Ace,
+            n @ 2..=9 => NormalCard::try_from(char::from_digit(n, 10).unwrap())?,
+            10 => NormalCard::Ten,
+            11 => NormalCard::Jack,
+            13 => NormalCard::Queen,
+            14 => NormalCard::King,
+            _ => return Err(c),
+        };
+
+        Ok(Self { rank, suit })
+    }
+}
+
+impl From<PlayingCard> for char {
+    fn from(card: PlayingCard) -> Self {
+        let suit = match card.suit {
+            Suit::Spades => 0x1f0a0,
+            Suit::Hearts => 0x1f0b0,
+            Suit::Diamonds => 0x1f0c0,
+            Suit::Clubs => 0x1f0d0,
+        };
+        let rank = match card.rank {
+            NormalCard::Ace => 1,
+            NormalCard::Jack => 11,
+            NormalCard::Queen => 13,
+            NormalCard::King => 14,
+            rank => rank as u32 + 2,
+        };
+
+        char::from_u32(suit + rank).unwrap()
+    }
+}
+
+impl Card for PlayingCard {
+    fn rank(&self) -> u8 {
+        self.rank as u8
+    }
+}
+
+impl Suited for PlayingCard {
+    fn suit(&self) -> Suit {
+        self.suit
+    }
+}
+
+impl<T: Card, const N: usize> Hand<T, N> {
+    pub fn new(cards: [T; N]) -> Self {
+        const { assert!(N <= 15, "hands have at most 15 cards") };
+
+        let kind = HandKind::classify(&cards);
+        let key = cards
+            .iter()
+            .fold(kind.0 as u128, |acc, card| acc << 4 | card.rank() as u128)
+            << (4 * (15 - N));
+
+        Self { cards, kind, key }
+    }
+
+    pub fn cmp_by<B: TieBreak<T>>(&self, other: &Self) -> Ordering {
+        self.kind
+            .cmp(&other.kind)
+            .then_with(|| B::cmp(&self.cards, &other.cards))
+    }
+}
+
+impl<T: Card, const N: usize> Ord for Hand<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<T: Card, const N: usize> PartialOrd for Hand<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Card, const N: usize> Hand<T, N> {
+    fn parse(y: usize, line: &str, text: &str) -> Result<Self, ParseError> {
+        let cards = text
+            .char_indices()
+            .map(|(i, c)| {
+                T::try_from(c).map_err(|_| {
+                    ParseError::at(y, line, &text[i..i + c.len_utf8()], "expected a card")
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseError::at(y, line, text, format!("expected {} cards", N)))?;
+
+        Ok(Self::new(cards))
+    }
+
+    pub fn cards(&self) -> &[T; N] {
+        &self.cards
+    }
+}
+
+pub trait HandChooser {
+    fn kind(&self) -> HandKind;
+}
+
+impl<T: Card, const N: usize> Hand<T, N> {
+    // The cards that aren't wild with their counts, biggest bucket first and
+    // then highest card first.
+    pub fn buckets(&self) -> Vec<(T, usize)> {
+        let mut cards: Vec<_> = self.cards.iter().filter(|card| !card.is_wild()).collect();
+        cards.sort_unstable();
+
+        let mut buckets: Vec<_> = cards
+            .chunk_by(|a, b| a == b)
+            .map(|bucket| (*bucket[0], bucket.len()))
+            .collect();
+        buckets.sort_unstable_by_key(|&(card, count)| Reverse((count, card)));
+        buckets
+    }
+
+    pub fn wildcards(&self) -> usize {
+        self.cards.iter().filter(|card| card.is_wild()).count()
+    }
+
+    // The card the wildcards stand in for, if there are any to assign.
+    pub fn assigned(&self) -> Option<T> {
+        (self.wildcards() > 0)
+            .then(|| self.buckets().first().map(|&(card, _)| card))
+            .flatten()
+    }
+}
+
+impl<T: Card, const N: usize> std::fmt::Display for Hand<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cards
+            .iter()
+            .try_for_each(|&card| write!(f, "{}", Into::<char>::into(card)))
+    }
+}
+
+impl<T: Card, const N: usize> HandChooser for Hand<T, N> {
+    fn kind(&self) -> HandKind {
+        self.kind
+    }
+}
+
+pub fn parse_hands<T: Card, const N: usize>(s: &str) -> Result<Vec<(Hand<T, N>, u32)>, ParseError> {
+    s.lines()
+        .zip(1..)
+        .map(|(line, y)| {
+            let mut fields = line.split_whitespace();
+            let (Some(hand), Some(bid), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(ParseError::at(y, line, line, "expected `<hand> <bid>`"));
+            };
+
+            Ok((Hand::parse(y, line, hand)?, number(y, line, bid)?))
+        })
+        .collect()
+}
+
+pub fn solve<B: TieBreak<T>, T: Card, const N: usize>(hands: &[(Hand<T, N>, u32)]) -> u32 {
+    let mut camel = hands.to_vec();
+
+    B::sort(&mut camel);
+
+    camel
+        .into_iter()
+        .zip(1..)
+        .fold(0, |acc, ((_, bid), i)| acc + i * bid)
+}
+
+pub struct Explanation<T: Card, const N: usize> {
+    pub hand: Hand<T, N>,
+    pub bid: u32,
+    pub kind: HandKind,
+    pub rank: u32,
+    pub winnings: u32,
+}
+
+impl<T: Card, const N: usize> Explanation<T, N> {
+    fn buckets(&self) -> Vec<String> {
+        self.hand
+            .buckets()
+            .into_iter()
+            .map(|(card, count)| format!("{}:{}", Into::<char>::into(card), count))
+            .collect()
+    }
+
+    fn wildcards(&self) -> String {
+        match self.hand.assigned() {
+            Some(card) => format!("{} -> {}", self.hand.wildcards(), Into::<char>::into(card)),
+            None if self.hand.wildcards() > 0 => format!("{} -> any", self.hand.wildcards()),
+            None => "-".to_string(),
+        }
+    }
+
+    pub fn json(&self) -> String {
+        let buckets: Vec<_> = self
+            .hand
+            .buckets()
+            .into_iter()
+            .map(|(card, count)| format!("[\"{}\",{}]", Into::<char>::into(card), count))
+            .collect();
+        let assigned = match self.hand.assigned() {
+            Some(card) => format!("\"{}\"", Into::<char>::into(card)),
+            None => "null".to_string(),
+        };
+
+        format!(
+            "{{\"rank\":{},\"hand\":\"{}\",\"bid\":{},\"buckets\":[{}],\"wildcards\":{},\"assigned\":{},\"kind\":\"{}\",\"winnings\":{}}}",
+            self.rank,
+            self.hand,
+            self.bid,
+            buckets.join(","),
+            self.hand.wildcards(),
+            assigned,
+            self.kind,
+            self.winnings
+        )
+    }
+}
+
+impl<T: Card, const N: usize> std::fmt::Display for Explanation<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>5}  {}  {:>5}  {:<20}  {:<8}  {:<16}  {:>8}",
+            self.rank,
+            self.hand,
+            self.bid,
+            self.buckets().join(" "),
+            self.wildcards(),
+            self.kind,
+            self.winnings
+        )
+    }
+}
+
+// Every hand in rank order with how it was classified, for auditing `solve`.
+pub fn explain<B: TieBreak<T>, T: Card, const N: usize>(
+    hands: &[(Hand<T, N>, u32)],
+) -> Vec<Explanation<T, N>> {
+    let mut camel = hands.to_vec();
+
+    camel.sort_by(|(this, _), (other, _)| this.cmp_by::<B>(other));
+
+    camel
+        .into_iter()
+        .zip(1..)
+        .map(|((hand, bid), rank)| Explanation {
+            kind: hand.kind(),
+            hand,
+            bid,
+            rank,
+            winnings: rank * bid,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+fn report<T: Card, const N: usize>(explanations: &[Explanation<T, N>], format: Format) -> String {
+    match format {
+        Format::Table => {
+            let mut lines = vec![format!(
+                "{:>5}  {:<w$}  {:>5}  {:<20}  {:<8}  {:<16}  {:>8}",
+                "rank",
+                "hand",
+                "bid",
+                "buckets",
+                "wild",
+                "kind",
+                "winnings",
+                w = N
+            )];
+            lines.extend(explanations.iter().map(ToString::to_string));
+            let total: u32 = explanations.iter().map(|e| e.winnings).sum();
+            lines.push(format!("total winnings: {}", total));
+            lines.join("\n")
+        }
+        Format::Json => {
+            let hands: Vec<_> = explanations.iter().map(Explanation::json).collect();
+            format!("[{}]", hands.join(","))
+        }
+    }
+}
+
+pub struct Hands {
+    normal: Vec<(Hand<NormalCard>, u32)>,
+    joker: Vec<(Hand<JokerCard>, u32)>,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Hands;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Hands {
+            normal: parse_hands(input)?,
+            joker: parse_hands(input)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u32> {
+        Ok(solve::<Positional, _, _>(&input.normal))
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u32> {
+        Ok(solve::<Positional, _, _>(&input.joker))
+    }
+}
+
+impl Hands {
+    pub fn explain(&self, format: Format) -> String {
+        let normal = report(&explain::<Positional, _, _>(&self.normal), format);
+        let joker = report(&explain::<Positional, _, _>(&self.joker), format);
+
+        match format {
+            Format::Table => format!("Part 1\n{}\n\nPart 2\n{}", normal, joker),
+            Format::Json => format!("{{\"part1\":{},\"part2\":{}}}", normal, joker),
+        }
+    }
+}
+
+pub const DAY: Day = Day::new::<Day7>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(Day7::solve1(EXAMPLE), Ok(6440));
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(Day7::solve2(EXAMPLE), Ok(5905));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day7::solve1("32T3K 765\nT55X5 684\n").map(|_| ()),
+            Err(ParseError::new(2, 4, "X", "expected a card").into())
+        );
+        assert_eq!(
+            Day7::solve1("32T3 765\n").map(|_| ()),
+            Err(ParseError::new(1, 1, "32T3", "expected 5 cards").into())
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    enum DoubleJoker {
+        Joker,
+        Wild,
+        Card(u8),
+    }
+
+    impl TryFrom<char> for DoubleJoker {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                'J' => Ok(Self::Joker),
+                'W' => Ok(Self::Wild),
+                '2'..='9' => Ok(Self::Card(c as u8 - b'0')),
+                _ => Err(c),
+            }
+        }
+    }
+
+    impl From<DoubleJoker> for char {
+        fn from(card: DoubleJoker) -> Self {
+            match card {
+                DoubleJoker::Joker => 'J',
+                DoubleJoker::Wild => 'W',
+                DoubleJoker::Card(n) => (b'0' + n) as char,
+            }
+        }
+    }
+
+    impl Card for DoubleJoker {
+        const WILDCARDS: &'static [Self] = &[DoubleJoker::Joker, DoubleJoker::Wild];
+
+        fn rank(&self) -> u8 {
+            match self {
+                DoubleJoker::Joker => 0,
+                DoubleJoker::Wild => 1,
+                DoubleJoker::Card(n) => *n,
+            }
+        }
+    }
+
+    fn kind<T: Card, const N: usize>(hand: &str) -> HandKind {
+        Hand::<T, N>::parse(1, hand, hand).unwrap().kind()
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(kind::<NormalCard, 5>("T55J5").buckets(), [3, 1, 1]);
+        assert_eq!(kind::<JokerCard, 5>("T55J5").buckets(), [4, 1]);
+        assert_eq!(kind::<JokerCard, 5>("JJJJJ").buckets(), [5]);
+        assert_eq!(kind::<JokerCard, 5>("KTJJT").to_string(), "four of a kind");
+        assert_eq!(kind::<DoubleJoker, 5>("2W3J4").buckets(), [3, 1, 1]);
+        assert_eq!(kind::<DoubleJoker, 7>("22W3J4J").buckets(), [5, 1, 1]);
+        assert_eq!(kind::<NormalCard, 7>("2233445").to_string(), "2-2-2-1");
+
+        assert!(kind::<NormalCard, 7>("2222333") > kind::<NormalCard, 7>("2222345"));
+        assert!(kind::<NormalCard, 7>("2223334") > kind::<NormalCard, 7>("2233445"));
+        assert!(kind::<NormalCard, 5>("AAKKQ") < kind::<NormalCard, 5>("2223K"));
+
+        let hands = parse_hands::<DoubleJoker, 7>("22W3J4J 2\n2345678 3\n").unwrap();
+        assert_eq!(solve::<Positional, _, _>(&hands), 7);
+    }
+
+    #[test]
+    fn test_tie_break() {
+        let hands = parse_hands::<NormalCard, 5>("33AA2 1\nAA224 2\n").unwrap();
+        assert_eq!(solve::<Positional, _, _>(&hands), 5);
+        assert_eq!(solve::<Poker, _, _>(&hands), 4);
+
+        let hands = parse_hands::<PlayingCard, 5>("🂡🃑🂢🂣🂤 1\n🂱🃁🃒🂳🃔 2\n").unwrap();
+        assert_eq!(hands[0].0.cmp_by::<Poker>(&hands[1].0), Ordering::Equal);
+        assert_eq!(solve::<SuitAware, _, _>(&hands), 4);
+    }
+
+    #[test]
+    fn test_explain() {
+        let hands = Day7::parse(EXAMPLE).unwrap();
+        let table = hands.explain(Format::Table);
+        assert!(table.contains(
+            "    5  KTJJT    220  T:2 K:1               2 -> T    four of a kind        1100"
+        ));
+        assert!(table.contains("total winnings: 5905"));
+
+        let json = hands.explain(Format::Json);
+        assert!(json.starts_with("{\"part1\":[{\"rank\":1,\"hand\":\"32T3K\",\"bid\":765,\"buckets\":[[\"3\",2],[\"K\",1],[\"T\",1],[\"2\",1]],\"wildcards\":0,\"assigned\":null,\"kind\":\"one pair\",\"winnings\":765}"));
+        assert!(json.contains("],\"part2\":[{\"rank\":1,"));
+
+        let hands = parse_hands::<JokerCard, 5>("JJJJJ 3\n").unwrap();
+        let explanation = &explain::<Positional, _, _>(&hands)[0];
+        assert_eq!(explanation.hand.to_string(), "JJJJJ");
+        assert_eq!(explanation.wildcards(), "5 -> any");
+        assert_eq!(explanation.kind.to_string(), "five of a kind");
+    }
+}
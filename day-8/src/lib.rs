@@ -0,0 +1,762 @@
+use std::{collections::HashMap, fmt};
+
+use common::{Answer, Day, ParseError, Solution};
+use lazy_static::lazy_static;
+use num::Integer;
+use regex::Regex;
+
+pub const INPUT: &str = include_str!("input");
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"^(\w{3}) = \((\w{3}), (\w{3})\)").unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Node(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Instruction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(c),
+        }
+    }
+}
+
+// Node names are interned in the order they are defined, so a node is an index
+// into `names` and `edges`.
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<Instruction>,
+    names: Vec<String>,
+    nodes: HashMap<String, Node>,
+    edges: Vec<(Node, Node)>,
+}
+
+// Where a walk is before taking its next step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct State {
+    pub node: Node,
+    pub instruction: usize,
+}
+
+pub struct Walk<'a> {
+    network: &'a Network,
+    state: State,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = State;
+
+    fn next(&mut self) -> Option<State> {
+        let state = self.state;
+        self.state = self.network.step(state);
+        Some(state)
+    }
+}
+
+impl Network {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn node(&self, name: &str) -> Option<Node> {
+        self.nodes.get(name).copied()
+    }
+
+    pub fn name(&self, node: Node) -> &str {
+        &self.names[node.0]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = Node> {
+        (0..self.names.len()).map(Node)
+    }
+
+    pub fn next(&self, node: Node, instruction: Instruction) -> Node {
+        let (left, right) = self.edges[node.0];
+
+        match instruction {
+            Instruction::Left => left,
+            Instruction::Right => right,
+        }
+    }
+
+    pub fn step(&self, state: State) -> State {
+        State {
+            node: self.next(state.node, self.instructions[state.instruction]),
+            instruction: (state.instruction + 1) % self.instructions.len(),
+        }
+    }
+
+    // Every state from `start` onwards, following the instructions forever.
+    pub fn walk(&self, start: Node) -> Walk<'_> {
+        Walk {
+            network: self,
+            state: State {
+                node: start,
+                instruction: 0,
+            },
+        }
+    }
+}
+
+// A goal node reached on a walk, and where in the instructions it was reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hit {
+    pub step: u64,
+    pub node: Node,
+    pub instruction: usize,
+}
+
+// A walk is deterministic in (node, instruction index), so it eventually loops:
+// the steps from `offset` onwards repeat every `period` steps. `hits` are the
+// goals reached before the first repeat, so those at or after `offset` recur.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub offset: u64,
+    pub period: u64,
+    pub hits: Vec<Hit>,
+}
+
+impl Cycle {
+    pub fn contains(&self, step: u64) -> bool {
+        let step = match step.checked_sub(self.offset) {
+            Some(n) => self.offset + n % self.period,
+            None => step,
+        };
+
+        self.hits.iter().any(|hit| hit.step == step)
+    }
+
+    fn residues(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits
+            .iter()
+            .filter(|hit| hit.step >= self.offset)
+            .map(|hit| hit.step % self.period)
+    }
+}
+
+impl Network {
+    pub fn cycle(&self, start: Node, goal: impl Fn(Node) -> bool) -> Cycle {
+        let width = self.instructions.len();
+        let mut seen = vec![None; self.names.len() * width];
+        let mut hits = Vec::new();
+
+        for (state, step) in self.walk(start).zip(0..) {
+            let seen = &mut seen[state.node.0 * width + state.instruction];
+            if let Some(offset) = *seen {
+                return Cycle {
+                    offset,
+                    period: step - offset,
+                    hits,
+                };
+            }
+            *seen = Some(step);
+
+            if goal(state.node) {
+                hits.push(Hit {
+                    step,
+                    node: state.node,
+                    instruction: state.instruction,
+                });
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalkError {
+    Unknown(String),
+    // The walk from `start` repeats a state without reaching a goal: after
+    // `offset` steps it goes round `cycle` forever.
+    Unreachable {
+        start: String,
+        offset: u64,
+        cycle: Vec<String>,
+    },
+    // There are no `..A` nodes for the ghosts to start from.
+    NoStarts,
+    // Every walk reaches goals, but never all at the same step.
+    Apart(Vec<String>),
+    // The walks do meet, but only past the last step a u64 can count.
+    Overflow,
+}
+
+impl fmt::Display for WalkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalkError::Unknown(node) => write!(f, "unknown node {}", node),
+            WalkError::Unreachable {
+                start,
+                offset,
+                cycle,
+            } => {
+                let shown = cycle.len().min(8);
+                write!(
+                    f,
+                    "no goal reachable from {}: from step {} it loops every {} steps through {}{}",
+                    start,
+                    offset,
+                    cycle.len(),
+                    cycle[..shown].join(", "),
+                    if shown < cycle.len() { ", ..." } else { "" }
+                )
+            }
+            WalkError::NoStarts => write!(f, "no start nodes ending in A"),
+            WalkError::Apart(starts) => write!(
+                f,
+                "the walks from {} are never at a goal together",
+                starts.join(", ")
+            ),
+            WalkError::Overflow => {
+                write!(f, "the walks only meet after more steps than fit in a u64")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WalkError {}
+
+impl Network {
+    fn lookup(&self, name: &str) -> Result<Node, WalkError> {
+        self.node(name)
+            .ok_or_else(|| WalkError::Unknown(name.to_string()))
+    }
+
+    fn unreachable(&self, start: Node, offset: u64, period: u64) -> WalkError {
+        WalkError::Unreachable {
+            start: self.name(start).to_string(),
+            offset,
+            cycle: self
+                .walk(start)
+                .skip(offset as usize)
+                .take(period as usize)
+                .map(|state| self.name(state.node).to_string())
+                .collect(),
+        }
+    }
+
+    // The number of steps to the first goal, stopping once the walk repeats.
+    pub fn reach(&self, start: Node, goal: impl Fn(Node) -> bool) -> Result<u64, WalkError> {
+        let width = self.instructions.len();
+        let mut seen = vec![None; self.names.len() * width];
+
+        for (state, step) in self.walk(start).zip(0..) {
+            if goal(state.node) {
+                return Ok(step);
+            }
+
+            let seen = &mut seen[state.node.0 * width + state.instruction];
+            if let Some(offset) = *seen {
+                return Err(self.unreachable(start, offset, step - offset));
+            }
+            *seen = Some(step);
+        }
+
+        unreachable!()
+    }
+
+    pub fn steps(&self, start: &str, goal: &str) -> Result<u64, WalkError> {
+        let (start, goal) = (self.lookup(start)?, self.lookup(goal)?);
+        self.reach(start, |node| node == goal)
+    }
+
+    // The first step at which the walks from every `..A` node are all on `..Z`
+    // nodes.
+    pub fn ghosts(&self) -> Result<u64, WalkError> {
+        let starts: Vec<_> = self
+            .nodes()
+            .filter(|&node| self.name(node).ends_with('A'))
+            .collect();
+        if starts.is_empty() {
+            return Err(WalkError::NoStarts);
+        }
+
+        let cycles = starts
+            .iter()
+            .map(|&start| {
+                let cycle = self.cycle(start, |node| self.name(node).ends_with('Z'));
+                if cycle.hits.is_empty() {
+                    return Err(self.unreachable(start, cycle.offset, cycle.period));
+                }
+                Ok(cycle)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        meet(&cycles)?.ok_or_else(|| {
+            WalkError::Apart(
+                starts
+                    .iter()
+                    .map(|&node| self.name(node).to_string())
+                    .collect(),
+            )
+        })
+    }
+}
+
+// What to draw over the network in `Network::dot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay<'a> {
+    Plain,
+    Path(&'a str, &'a str),
+    Ghosts,
+}
+
+const COLOURS: [&str; 6] = ["red", "blue", "green3", "orange", "purple", "brown"];
+
+impl Network {
+    // The states from `start` up to, but not including, reaching `goal`.
+    pub fn path(&self, start: &str, goal: &str) -> Result<Vec<State>, WalkError> {
+        let steps = self.steps(start, goal)?;
+        Ok(self
+            .walk(self.lookup(start)?)
+            .take(steps as usize)
+            .collect())
+    }
+
+    // The states each `..A` walk goes round once it is looping.
+    pub fn loops(&self) -> Vec<Vec<State>> {
+        self.nodes()
+            .filter(|&node| self.name(node).ends_with('A'))
+            .map(|start| {
+                let cycle = self.cycle(start, |_| false);
+                self.walk(start)
+                    .skip(cycle.offset as usize)
+                    .take(cycle.period as usize)
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Graphviz source for the network, with `..A` and `..Z` nodes styled and
+    // the edges taken by the overlaid walks coloured, one colour per walk.
+    pub fn dot(&self, overlay: Overlay) -> Result<String, WalkError> {
+        let walks = match overlay {
+            Overlay::Plain => Vec::new(),
+            Overlay::Path(start, goal) => vec![self.path(start, goal)?],
+            Overlay::Ghosts => self.loops(),
+        };
+
+        let mut highlighted = HashMap::new();
+        for (walk, colour) in walks.iter().zip(COLOURS.iter().cycle()) {
+            for state in walk {
+                let edge = (state.node, self.instructions[state.instruction]);
+                highlighted.entry(edge).or_insert(*colour);
+            }
+        }
+
+        let edge = |from: Node, to: Node, label: &str, instructions: &[Instruction]| {
+            let colour = instructions
+                .iter()
+                .find_map(|&instruction| highlighted.get(&(from, instruction)));
+            let style = match colour {
+                Some(colour) => format!(", color={}, penwidth=2", colour),
+                None => String::new(),
+            };
+            format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"{}];",
+                self.name(from),
+                self.name(to),
+                label,
+                style
+            )
+        };
+
+        let mut lines = vec!["digraph network {".to_string()];
+        for node in self.nodes() {
+            let name = self.name(node);
+            if name.ends_with('A') {
+                lines.push(format!(
+                    "    \"{}\" [shape=box, style=filled, fillcolor=palegreen];",
+                    name
+                ));
+            } else if name.ends_with('Z') {
+                lines.push(format!(
+                    "    \"{}\" [shape=doublecircle, style=filled, fillcolor=lightpink];",
+                    name
+                ));
+            }
+
+            let (left, right) = self.edges[node.0];
+            if left == right {
+                lines.push(edge(
+                    node,
+                    left,
+                    "L/R",
+                    &[Instruction::Left, Instruction::Right],
+                ));
+            } else {
+                lines.push(edge(node, left, "L", &[Instruction::Left]));
+                lines.push(edge(node, right, "R", &[Instruction::Right]));
+            }
+        }
+        lines.push("}".to_string());
+
+        Ok(lines.join("\n"))
+    }
+}
+
+// The combined modulus of some congruences doesn't fit in an i128.
+#[derive(Debug, PartialEq, Eq)]
+struct ModulusOverflow;
+
+// x = a (mod m) and x = b (mod n) as one congruence, if they are compatible.
+fn crt(
+    (a, m): (i128, i128),
+    (b, n): (i128, i128),
+) -> Result<Option<(i128, i128)>, ModulusOverflow> {
+    let gcd = m.extended_gcd(&n);
+    if (b - a) % gcd.gcd != 0 {
+        return Ok(None);
+    }
+
+    let lcm = (m / gcd.gcd).checked_mul(n).ok_or(ModulusOverflow)?;
+    // Both factors are reduced first, so this only overflows for huge n.
+    let n_g = n / gcd.gcd;
+    let k = ((b - a) / gcd.gcd)
+        .rem_euclid(n_g)
+        .checked_mul(gcd.x.rem_euclid(n_g))
+        .ok_or(ModulusOverflow)?
+        % n_g;
+    let x = m
+        .checked_mul(k)
+        .and_then(|mk| a.checked_add(mk))
+        .ok_or(ModulusOverflow)?;
+    Ok(Some((x.rem_euclid(lcm), lcm)))
+}
+
+// Past this many candidate congruences the cycles are stepped through instead.
+const CONGRUENCES: usize = 1 << 12;
+
+// The first step at which every walk is at a goal. Before the last walk has
+// started looping that is checked directly, afterwards each walk allows a set
+// of residues modulo its period and those are combined. When there are too
+// many of those, or their modulus outgrows an i128, the cycles are stepped
+// through instead.
+pub fn meet(cycles: &[Cycle]) -> Result<Option<u64>, WalkError> {
+    let Some(start) = cycles.iter().map(|cycle| cycle.offset).max() else {
+        return Ok(None);
+    };
+    if let Some(step) = (0..start).find(|&step| cycles.iter().all(|c| c.contains(step))) {
+        return Ok(Some(step));
+    }
+
+    let mut congruences = vec![(0, 1)];
+    for cycle in cycles {
+        let combined = congruences
+            .iter()
+            .flat_map(|&congruence| {
+                cycle
+                    .residues()
+                    .map(move |r| crt(congruence, (r as i128, cycle.period as i128)))
+            })
+            .collect::<Result<Vec<_>, _>>();
+        let Ok(combined) = combined else {
+            return Ok(simulate(cycles, start));
+        };
+
+        congruences = combined.into_iter().flatten().collect();
+        congruences.sort_unstable();
+        congruences.dedup();
+
+        if congruences.len() > CONGRUENCES {
+            return Ok(simulate(cycles, start));
+        }
+    }
+
+    let Some(after) = congruences
+        .into_iter()
+        .map(|(r, m)| (r - start as i128).rem_euclid(m))
+        .min()
+    else {
+        return Ok(None);
+    };
+
+    u64::try_from(after)
+        .ok()
+        .and_then(|after| start.checked_add(after))
+        .map(Some)
+        .ok_or(WalkError::Overflow)
+}
+
+// Steps through the goals of the walk with the longest period until the
+// combined pattern repeats.
+fn simulate(cycles: &[Cycle], start: u64) -> Option<u64> {
+    let longest = cycles.iter().max_by_key(|cycle| cycle.period)?;
+    let repeat = cycles
+        .iter()
+        .try_fold(1u64, |acc, cycle| {
+            acc.checked_mul(cycle.period / acc.gcd(&cycle.period))
+        })
+        .unwrap_or(u64::MAX);
+    let mut residues: Vec<_> = longest.residues().collect();
+    residues.sort_unstable();
+    if residues.is_empty() {
+        return None;
+    }
+
+    (start / longest.period..)
+        .flat_map(|n| residues.iter().map(move |r| n * longest.period + r))
+        .skip_while(|&step| step < start)
+        .take_while(|&step| step - start < repeat)
+        .find(|&step| cycles.iter().all(|cycle| cycle.contains(step)))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().zip(1..);
+        let (first, _) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing instructions"))?;
+
+        let instructions = first
+            .char_indices()
+            .map(|(i, c)| {
+                Instruction::try_from(c).map_err(|_| {
+                    ParseError::at(1, first, &first[i..i + c.len_utf8()], "expected L or R")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(ParseError::new(1, 1, "", "missing instructions"));
+        }
+
+        let mut definitions = Vec::new();
+        let mut names = Vec::new();
+        let mut nodes = HashMap::new();
+        for (line, y) in lines.skip_while(|(line, _)| line.is_empty()) {
+            let (_, [key, left, right]) = RE
+                .captures(line)
+                .ok_or_else(|| ParseError::at(y, line, line, "expected `AAA = (BBB, CCC)`"))?
+                .extract();
+            if nodes.insert(key.to_string(), Node(names.len())).is_some() {
+                return Err(ParseError::at(y, line, key, "duplicate node"));
+            }
+            names.push(key.to_string());
+            definitions.push((y, line, left, right));
+        }
+
+        let node = |y, line, name| {
+            nodes
+                .get(name)
+                .copied()
+                .ok_or_else(|| ParseError::at(y, line, name, "unknown node"))
+        };
+        let edges = definitions
+            .into_iter()
+            .map(|(y, line, left, right)| Ok((node(y, line, left)?, node(y, line, right)?)))
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Network {
+            instructions,
+            names,
+            nodes,
+            edges,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer<u64> {
+        Ok(input.steps("AAA", "ZZZ")?)
+    }
+
+    fn part2(input: &Self::Input) -> Answer<u64> {
+        Ok(input.ghosts()?)
+    }
+}
+
+pub const DAY: Day = Day::new::<Day8>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example-1");
+    const EXAMPLE2: &str = include_str!("example-2");
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(Day8::solve1(EXAMPLE1), Ok(6));
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(Day8::solve2(EXAMPLE2), Ok(6));
+    }
+
+    #[test]
+    fn test_cycles() {
+        let network = Day8::parse(
+            "L\n\n\
+             11A = (11B, XXX)\n11B = (11Z, XXX)\n11Z = (11B, XXX)\n\
+             22A = (22Z, XXX)\n22Z = (22B, XXX)\n22B = (22C, XXX)\n22C = (22Z, XXX)\n\
+             33A = (33Z, XXX)\n33Z = (33B, XXX)\n33B = (33B, XXX)\n\
+             XXX = (XXX, XXX)\n",
+        )
+        .unwrap();
+        let node = |name| network.node(name).unwrap();
+        let cycle = |start| network.cycle(node(start), |n| network.name(n).ends_with('Z'));
+
+        assert_eq!(
+            cycle("22A"),
+            Cycle {
+                offset: 1,
+                period: 3,
+                hits: vec![Hit {
+                    step: 1,
+                    node: node("22Z"),
+                    instruction: 0
+                }]
+            }
+        );
+
+        // The first hits are at 2 and 1, but 11A only hits on even steps.
+        assert_eq!(meet(&[cycle("11A"), cycle("22A")]), Ok(Some(4)));
+        assert_eq!(meet(&[cycle("22A"), cycle("33A")]), Ok(Some(1)));
+        assert_eq!(meet(&[cycle("11A"), cycle("33A")]), Ok(None));
+        assert_eq!(simulate(&[cycle("11A"), cycle("22A")], 1), Some(4));
+        assert_eq!(crt((2, 4), (3, 6)), Ok(None));
+        assert_eq!(crt((2, 4), (4, 6)), Ok(Some((10, 12))));
+        assert_eq!(
+            crt((0, 1 << 100), (1, (1 << 100) - 1)),
+            Err(ModulusOverflow)
+        );
+
+        // A goal at `step` and then every `period` steps.
+        let every = |period: u64, step: u64| Cycle {
+            offset: 0,
+            period,
+            hits: vec![Hit {
+                step,
+                node: Node(0),
+                instruction: 0,
+            }],
+        };
+        // The combined period is past i128, but the walks meet early on.
+        let periods = [1 << 50, (1 << 50) - 1, (1 << 50) - 3];
+        assert_eq!(meet(&periods.map(|period| every(period, 5))), Ok(Some(5)));
+        // The walks meet around step 2^126.
+        assert_eq!(
+            meet(&[every(1 << 63, 1), every((1 << 63) - 1, 0)]),
+            Err(WalkError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_network() {
+        let network = Day8::parse(EXAMPLE1).unwrap();
+        let aaa = network.node("AAA").unwrap();
+        let walk: Vec<_> = network
+            .walk(aaa)
+            .take(4)
+            .map(|state| (network.name(state.node), state.instruction))
+            .collect();
+        assert_eq!(walk, [("AAA", 0), ("BBB", 1), ("AAA", 2), ("BBB", 0)]);
+
+        assert_eq!(
+            Day8::solve1("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, CCC)\n").map(|_| ()),
+            Err(ParseError::new(3, 8, "BBB", "unknown node").into())
+        );
+        assert_eq!(
+            Day8::solve1("LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n").map(|_| ()),
+            Err(ParseError::new(4, 1, "AAA", "duplicate node").into())
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let network = Day8::parse(
+            "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, CCC)\nCCC = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)\n",
+        )
+        .unwrap();
+        let err = network.steps("AAA", "ZZZ").unwrap_err();
+        assert_eq!(
+            err,
+            WalkError::Unreachable {
+                start: "AAA".to_string(),
+                offset: 1,
+                cycle: vec!["BBB".to_string(), "CCC".to_string()]
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "no goal reachable from AAA: from step 1 it loops every 2 steps through BBB, CCC"
+        );
+        assert_eq!(
+            network.steps("AAA", "YYY"),
+            Err(WalkError::Unknown("YYY".to_string()))
+        );
+        assert_eq!(
+            Day8::part1(&network).map_err(|err| err.to_string()),
+            Err(err.to_string())
+        );
+
+        let network = Day8::parse(
+            "L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n22A = (22B, 22B)\n22B = (22B, 22B)\n",
+        )
+        .unwrap();
+        assert_eq!(
+            network.ghosts(),
+            Err(WalkError::Unreachable {
+                start: "22A".to_string(),
+                offset: 1,
+                cycle: vec!["22B".to_string()]
+            })
+        );
+
+        let network =
+            Day8::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22Z, 22Z)\n")
+                .unwrap();
+        assert_eq!(
+            network.ghosts(),
+            Err(WalkError::Apart(vec!["11A".to_string(), "22A".to_string()]))
+        );
+
+        let network = Day8::parse("L\n\nBBB = (BBB, BBB)\n").unwrap();
+        assert_eq!(network.ghosts(), Err(WalkError::NoStarts));
+        assert_eq!(
+            Day8::part2(&network).map_err(|err| err.to_string()),
+            Err("no start nodes ending in A".to_string())
+        );
+    }
+
+    #[test]
+    fn test_dot() {
+        let network = Day8::parse(EXAMPLE1).unwrap();
+        assert_eq!(
+            network.dot(Overlay::Path("AAA", "ZZZ")).unwrap(),
+            "digraph network {\n    \
+             \"AAA\" [shape=box, style=filled, fillcolor=palegreen];\n    \
+             \"AAA\" -> \"BBB\" [label=\"L/R\", color=red, penwidth=2];\n    \
+             \"BBB\" -> \"AAA\" [label=\"L\", color=red, penwidth=2];\n    \
+             \"BBB\" -> \"ZZZ\" [label=\"R\", color=red, penwidth=2];\n    \
+             \"ZZZ\" [shape=doublecircle, style=filled, fillcolor=lightpink];\n    \
+             \"ZZZ\" -> \"ZZZ\" [label=\"L/R\"];\n\
+             }"
+        );
+
+        let network = Day8::parse(EXAMPLE2).unwrap();
+        let dot = network.dot(Overlay::Ghosts).unwrap();
+        assert!(dot.contains("\"11Z\" -> \"11B\" [label=\"L\", color=red, penwidth=2];"));
+        assert!(dot.contains("\"22B\" -> \"22C\" [label=\"L/R\", color=blue, penwidth=2];"));
+        assert!(!network.dot(Overlay::Plain).unwrap().contains(", color="));
+    }
+}
@@ -0,0 +1,414 @@
+use std::{
+    fmt::{self, Debug, Display},
+    iter,
+    str::FromStr,
+};
+
+use common::{error::number, Answer, Day, ParseError, Solution};
+use num::{
+    rational::Ratio, BigInt, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive,
+    Integer, Num, One, Signed,
+};
+
+pub const INPUT: &str = include_str!("input");
+
+pub trait Value:
+    Integer + Signed + CheckedAdd + CheckedSub + CheckedMul + FromPrimitive + Clone + Debug + FromStr
+{
+}
+
+impl<T> Value for T where
+    T: Integer
+        + Signed
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + FromPrimitive
+        + Clone
+        + Debug
+        + FromStr
+{
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryError {
+    Overflow,
+    // The differences run out before reaching an all-zero row. Any n values
+    // fit some polynomial of degree below n, but getting that row takes one of
+    // degree at most n - 2.
+    NotPolynomial,
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Overflow => write!(f, "differences overflow"),
+            HistoryError::NotPolynomial => write!(f, "differences never reach zero"),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+fn differences<T: Value>(row: &[T]) -> Option<Vec<T>> {
+    row.windows(2)
+        .map(|pair| pair[1].checked_sub(&pair[0]))
+        .collect()
+}
+
+// A sequence in Newton's forward-difference form: the first value of each row
+// of differences, down to the last non-zero row. The value at any index k is
+// then the sum of coefficient j times binomial(k, j). All arithmetic is
+// checked, with None meaning that a binomial, a term or a partial sum of them
+// didn't fit in T.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History<T = i64> {
+    values: Vec<T>,
+    coefficients: Vec<T>,
+}
+
+impl<T: Value> History<T> {
+    pub fn new(values: &[T]) -> Result<Self, HistoryError> {
+        let mut row = values.to_vec();
+        let mut coefficients = Vec::new();
+
+        while row.iter().any(|n| !n.is_zero()) {
+            if row.len() == 1 {
+                return Err(HistoryError::NotPolynomial);
+            }
+
+            coefficients.push(row[0].clone());
+            row = differences(&row).ok_or(HistoryError::Overflow)?;
+        }
+
+        Ok(Self {
+            values: values.to_vec(),
+            coefficients,
+        })
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    // The values and each row of differences below them, ending with the
+    // all-zero row.
+    pub fn pyramid(&self) -> Vec<Vec<T>> {
+        let mut rows = vec![self.values.clone()];
+        while let Some(row) = rows.last().filter(|row| row.iter().any(|n| !n.is_zero())) {
+            rows.push(differences(row).unwrap());
+        }
+
+        rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    // The degree of the polynomial through the values, -1 when they are all 0.
+    pub fn degree(&self) -> isize {
+        self.coefficients.len() as isize - 1
+    }
+
+    // Sums the terms, with `step` getting from binomial(k, j) to
+    // binomial(k, j + 1) given the former, k and j.
+    fn newton<N>(
+        &self,
+        k: &N,
+        lift: impl Fn(&T) -> N,
+        step: impl Fn(&N, &N, &N) -> Option<N>,
+    ) -> Option<N>
+    where
+        N: Num + CheckedAdd + CheckedMul + Clone,
+    {
+        let mut binomial = N::one();
+        let mut value = N::zero();
+        let mut j = N::zero();
+
+        for (i, coefficient) in self.coefficients.iter().enumerate() {
+            value = value.checked_add(&lift(coefficient).checked_mul(&binomial)?)?;
+            if i + 1 < self.coefficients.len() {
+                binomial = step(&binomial, k, &j)?;
+                j = j + N::one();
+            }
+        }
+
+        Some(value)
+    }
+
+    // binomial(k, j + 1) = binomial(k, j) * (k - j) / (j + 1), which is exact
+    // for negative k too. Dividing by the common factors first means the
+    // product is the result itself, so it only overflows if that doesn't fit.
+    pub fn at(&self, k: &T) -> Option<T> {
+        self.newton(k, T::clone, |binomial, k, j| {
+            let next = j.clone() + T::one();
+            let common = binomial.gcd(&next);
+            (binomial.clone() / common.clone()).checked_mul(&(k.checked_sub(j)? / (next / common)))
+        })
+    }
+
+    // The same for fractional k, where the division is always exact and
+    // multiplying ratios already cancels common factors first.
+    pub fn at_ratio(&self, k: &Ratio<T>) -> Option<Ratio<T>> {
+        self.newton(
+            k,
+            |coefficient| Ratio::from_integer(coefficient.clone()),
+            |binomial, k, j| {
+                let next = j.clone() + Ratio::one();
+                binomial.checked_mul(&k.checked_sub(j)?.checked_div(&next)?)
+            },
+        )
+    }
+
+    pub fn next(&self) -> Option<T> {
+        self.at(&T::from_usize(self.len())?)
+    }
+
+    pub fn previous(&self) -> Option<T> {
+        self.at(&-T::one())
+    }
+}
+
+impl<T: Value + Display> History<T> {
+    // The pyramid with the values before and after the history added to every
+    // row, as in the puzzle. None when those don't fit in T.
+    pub fn extended(&self) -> Option<Vec<Vec<T>>> {
+        let top: Vec<_> = iter::once(self.previous()?)
+            .chain(self.values.iter().cloned())
+            .chain(iter::once(self.next()?))
+            .collect();
+
+        let mut rows = vec![top];
+        for _ in 1..self.pyramid().len() {
+            rows.push(differences(rows.last().unwrap())?);
+        }
+
+        Some(rows)
+    }
+
+    // Each row is indented by half a cell more than the one above, so every
+    // difference sits between the two numbers it came from.
+    pub fn render(&self) -> Option<String> {
+        let rows = self.extended()?;
+        let width = rows
+            .iter()
+            .flatten()
+            .map(|n| n.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let step = (width + 2) & !1;
+
+        let lines: Vec<_> = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let cells: Vec<_> = row.iter().map(|n| format!("{:>width$}", n)).collect();
+                let line = " ".repeat(i * step / 2) + &cells.join(&" ".repeat(step - width));
+                line.trim_end().to_string()
+            })
+            .collect();
+
+        Some(lines.join("\n"))
+    }
+
+    // The same staggered layout as a table, with a column for every index and
+    // one between each pair for the differences. Extrapolated values are bold.
+    pub fn markdown(&self) -> Option<String> {
+        let rows = self.extended()?;
+        let columns = 2 * rows[0].len() - 1;
+        let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+
+        let header = (0..columns)
+            .map(|column| match column % 2 {
+                0 => (column as isize / 2 - 1).to_string(),
+                _ => String::new(),
+            })
+            .collect();
+        let mut lines = vec![line(header), line(vec![":-:".to_string(); columns])];
+
+        for (i, row) in rows.iter().enumerate() {
+            let cells = (0..columns)
+                .map(|column| {
+                    let j = column.checked_sub(i).filter(|j| j % 2 == 0).map(|j| j / 2);
+                    match j.and_then(|j| Some((j, row.get(j)?))) {
+                        Some((j, n)) if j == 0 || j == row.len() - 1 => format!("**{}**", n),
+                        Some((_, n)) => n.to_string(),
+                        None => String::new(),
+                    }
+                })
+                .collect();
+            lines.push(line(cells));
+        }
+
+        Some(lines.join("\n"))
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<History<BigInt>>;
+    type Answer1 = BigInt;
+    type Answer2 = BigInt;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .zip(1..)
+            .map(|(line, y)| {
+                let values = line
+                    .split_whitespace()
+                    .map(|s| number(y, line, s))
+                    .collect::<Result<Vec<_>, _>>()?;
+                History::new(&values).map_err(|err| ParseError::at(y, line, line, err.to_string()))
+            })
+            .collect()
+    }
+
+    // Big integers don't overflow, so the extrapolations should always
+    // succeed; the error is only passed on in case they somehow don't.
+    fn part1(input: &Self::Input) -> Answer<BigInt> {
+        input
+            .iter()
+            .map(|history| history.next().ok_or(HistoryError::Overflow))
+            .sum::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    fn part2(input: &Self::Input) -> Answer<BigInt> {
+        input
+            .iter()
+            .map(|history| history.previous().ok_or(HistoryError::Overflow))
+            .sum::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+}
+
+pub const DAY: Day = Day::new::<Day9>(INPUT);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example");
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(Day9::solve1(EXAMPLE), Ok(BigInt::from(114)));
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(Day9::solve2(EXAMPLE), Ok(BigInt::from(2)));
+    }
+
+    #[test]
+    fn test_history() {
+        let history = History::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(history.coefficients(), [10, 3, 0, 2]);
+        assert_eq!(history.degree(), 3);
+        assert_eq!((history.previous(), history.next()), (Some(5), Some(68)));
+
+        let cubic = |k: i64| 10 + 3 * k + k * (k - 1) * (k - 2) / 3;
+        for k in [-1000, -7, 0, 5, 1000] {
+            assert_eq!(history.at(&k), Some(cubic(k)));
+        }
+
+        let linear = History::new(&[0i64, 3, 6, 9, 12, 15]).unwrap();
+        assert_eq!(linear.at(&1000), Some(3000));
+        assert_eq!(History::new(&[0i64, 0, 0]).unwrap().degree(), -1);
+        assert_eq!(History::new(&[0i64, 0, 0]).unwrap().next(), Some(0));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(
+            History::new(&[i64::MAX, i64::MIN]),
+            Err(HistoryError::Overflow)
+        );
+
+        let squares = History::new(&[0i64, 1, 4, 9]).unwrap();
+        assert_eq!(squares.at(&(1 << 31)), Some(1 << 62));
+        assert_eq!(squares.at(&(1 << 32)), None);
+
+        // binomial(k, 2) fits even though k * (k - 1) doesn't.
+        let history = History::new(&[0i64, 0, 1, 3, 6]).unwrap();
+        assert_eq!(history.at(&(1 << 32)), Some(9223372034707292160));
+
+        let squares = History::new(&[0i128, 1, 4, 9]).unwrap();
+        assert_eq!(squares.at(&(1 << 32)), Some(1 << 64));
+
+        let values: Vec<_> = [0, 1, 4, 9].map(BigInt::from).into();
+        let squares = History::new(&values).unwrap();
+        let k = BigInt::from(10).pow(30);
+        assert_eq!(squares.at(&k), Some(k.pow(2)));
+
+        let squares = History::new(&[0i64, 1, 4, 9]).unwrap();
+        assert_eq!(squares.at_ratio(&Ratio::new(1, 2)), Some(Ratio::new(1, 4)));
+
+        // 10 + 3x + 2 binomial(x, 3) at x = -1/3.
+        let history = History::new(&[10i64, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(
+            history.at_ratio(&Ratio::new(-1, 3)),
+            Some(Ratio::new(701, 81))
+        );
+    }
+
+    #[test]
+    fn test_not_polynomial() {
+        assert_eq!(
+            History::new(&[1i64, 2, 4, 8]),
+            Err(HistoryError::NotPolynomial)
+        );
+        assert_eq!(History::new(&[1i64, 2, 4, 7]).unwrap().degree(), 2);
+        assert_eq!(History::new(&[5i64]), Err(HistoryError::NotPolynomial));
+        assert_eq!(History::new(&[0i64]).unwrap().degree(), -1);
+
+        assert_eq!(
+            Day9::solve1("0 3 6\n1 2 4 8\n"),
+            Err(ParseError::new(2, 1, "1 2 4 8", "differences never reach zero").into())
+        );
+
+        let history = History::new(&[1i64, 3, 6, 10]).unwrap();
+        assert_eq!(
+            history.pyramid(),
+            [vec![1, 3, 6, 10], vec![2, 3, 4], vec![1, 1], vec![0]]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let history = History::new(&[10i64, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(
+            history.render().unwrap(),
+            [
+                " 5  10  13  16  21  30  45  68",
+                "   5   3   3   5   9  15  23",
+                "    -2   0   2   4   6   8",
+                "       2   2   2   2   2",
+                "         0   0   0   0",
+            ]
+            .join("\n")
+        );
+
+        let history = History::new(&[1i64, 1]).unwrap();
+        assert_eq!(
+            history.markdown().unwrap(),
+            "| -1 |  | 0 |  | 1 |  | 2 |\n\
+             | :-: | :-: | :-: | :-: | :-: | :-: | :-: |\n\
+             | **1** |  | 1 |  | 1 |  | **1** |\n\
+             |  | **0** |  | 0 |  | **0** |  |"
+        );
+    }
+}
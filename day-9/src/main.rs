@@ -1,71 +1,52 @@
-const INPUT: &'static str = include_str!("input");
-
-fn main() {
-    println!("{}", solve1(INPUT));
-    println!("{}", solve2(INPUT));
-}
-
-fn solve1(s: &str) -> i32 {
-    s.lines()
-        .map(|line| {
-            let mut nums: Vec<_> = line
-                .split_whitespace()
-                .map(|s| s.parse::<i32>().unwrap())
-                .collect();
-
-            let mut sequences = Vec::new();
-
-            while !nums.iter().all(|n| *n == 0) {
-                sequences.push(nums.clone());
-                nums = nums.windows(2).map(|nums| nums[1] - nums[0]).collect();
-            }
-
-            sequences
-                .into_iter()
-                .map(|seq| seq.last().cloned().unwrap())
-                .sum::<i32>()
-        })
-        .sum()
-}
-
-fn solve2(s: &str) -> i32 {
-    s.lines()
-        .map(|line| {
-            let mut nums: Vec<_> = line
-                .split_whitespace()
-                .map(|s| s.parse::<i32>().unwrap())
+use std::process::ExitCode;
+
+use common::{input::Args, Solution};
+use day_9::{Day9, History, INPUT};
+use num::BigInt;
+
+type Render = fn(&History<BigInt>) -> Option<String>;
+
+fn main() -> ExitCode {
+    // `--pyramid` prints every history's difference pyramid instead of the
+    // answers.
+    let args = Args::from_env_with(&["pyramid"]);
+
+    let render: Option<Render> = match args.flag("pyramid") {
+        None => None,
+        Some(None | Some("text")) => Some(History::<BigInt>::render),
+        Some(Some("markdown")) => Some(History::<BigInt>::markdown),
+        Some(Some(value)) => {
+            eprintln!(
+                "unexpected argument --pyramid={}, expected --pyramid[=text|markdown]",
+                value
+            );
+            return ExitCode::from(2);
+        }
+    };
+
+    let input = args.input(INPUT);
+    let Some(render) = render else {
+        return common::run::<Day9>(&input, args.time);
+    };
+
+    match Day9::parse(&input) {
+        Ok(histories) => {
+            let pyramids: Vec<_> = histories
+                .iter()
+                .map(|history| {
+                    let degree = match history.degree() {
+                        -1 => "All zeros".to_string(),
+                        degree => format!("Degree {}", degree),
+                    };
+                    format!("{}:\n\n{}", degree, render(history).unwrap())
+                })
                 .collect();
-
-            let mut sequences = Vec::new();
-
-            while !nums.iter().all(|n| *n == 0) {
-                sequences.push(nums.clone());
-                nums = nums.windows(2).map(|nums| nums[1] - nums[0]).collect();
-            }
-
-            sequences
-                .into_iter()
-                .map(|seq| seq.first().cloned().unwrap())
-                .rev()
-                .reduce(|acc, n| n - acc)
-                .unwrap()
-        })
-        .sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE: &'static str = include_str!("example");
-
-    #[test]
-    fn test_solution1() {
-        assert_eq!(solve1(EXAMPLE), 114);
-    }
-
-    #[test]
-    fn test_solution2() {
-        assert_eq!(solve2(EXAMPLE), 2);
+            println!("{}", pyramids.join("\n\n"));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
     }
 }